@@ -0,0 +1,144 @@
+use crate::Part;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+pub fn default_answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.txt")
+}
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("failed to read {}: {source}", .path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("line {line}: expected `<day> <part> <input> <answer>', got `{text}'")]
+    Malformed { line: usize, text: String },
+    #[error("line {line}: duplicate answer for day {day} part {part} input {input}")]
+    Duplicate {
+        line: usize,
+        day: u8,
+        part: Part,
+        input: String,
+    },
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+// expected answers keyed by day, part and the name of the input they belong to
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: BTreeMap<(u8, Part, String), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = fs::read_to_string(path).map_err(|source| AnswersError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Answers::parse(&text)
+    }
+
+    // one answer per line, empty lines and lines starting with # are skipped
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let mut answers = Answers::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let line_nr = i + 1;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let malformed = || AnswersError::Malformed {
+                line: line_nr,
+                text: line.to_string(),
+            };
+            let fields = trimmed.splitn(4, char::is_whitespace).collect::<Vec<_>>();
+            if fields.len() != 4 {
+                return Err(malformed());
+            }
+            let day = fields[0].parse::<u8>().map_err(|_| malformed())?;
+            let part = Part::try_from(fields[1]).map_err(|_| malformed())?;
+            let input = fields[2].to_string();
+            let answer = fields[3].trim().to_string();
+
+            let key = (day, part, input);
+            if answers.entries.contains_key(&key) {
+                let (day, part, input) = key;
+                return Err(AnswersError::Duplicate {
+                    line: line_nr,
+                    day,
+                    part,
+                    input,
+                });
+            }
+            answers.entries.insert(key, answer);
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.entries
+            .get(&(day, part, input.to_string()))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: &str, answer: &str) -> Verdict {
+        match self.get(day, part, input) {
+            Some(expected) if expected == answer.trim() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let text = "# day part input answer\n\n1 1 input_day1 7\n1 2 example 5\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.get(1, Part::One, "input_day1"), Some("7"));
+        assert_eq!(
+            answers.check(1, Part::One, "input_day1", "7"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(1, Part::One, "input_day1", "8"),
+            Verdict::Fail {
+                expected: "7".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, "input_day1", "5"),
+            Verdict::Missing
+        );
+        assert_eq!(answers.check(1, Part::Two, "example", "5"), Verdict::Pass);
+
+        let answers = Answers::load(&default_answers_path()).unwrap();
+        assert_eq!(answers.get(1, Part::One, "input_day1"), Some("1226"));
+
+        let err = Answers::parse("1 1 input_day1 7\n1 1 input_day1 8").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: duplicate answer for day 1 part 1 input input_day1"
+        );
+        let err = Answers::parse("1 3 input_day1 7").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: expected `<day> <part> <input> <answer>', got `1 3 input_day1 7'"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{Day, ParseError, Part};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl TryFrom<&str> for Phase {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Solve(Part::One)),
+            "part2" => Ok(Phase::Solve(Part::Two)),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "need at least one sample");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        Stats {
+            min: sorted[0],
+            median: sorted[sorted.len() / 2],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+fn sample<T>(runs: usize, mut f: impl FnMut() -> T) -> (Stats, T) {
+    let mut samples = vec![];
+    let mut result = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        result = Some(f());
+        samples.push(start.elapsed());
+    }
+    (Stats::from_samples(&samples), result.unwrap())
+}
+
+// times parse and every part of a day separately, each measured runs times
+pub fn bench_day(
+    day: &Day,
+    lines: &[String],
+    runs: usize,
+) -> Result<Vec<(Phase, Stats)>, ParseError> {
+    let runner = day.runner;
+    let (parse_stats, input) = sample(runs, || runner.parse(lines));
+    let input = input?;
+
+    let mut results = vec![(Phase::Parse, parse_stats)];
+    for part in runner.parts() {
+        let (stats, _) = sample(runs, || runner.solve(input.as_ref(), *part));
+        results.push((Phase::Solve(*part), stats));
+    }
+    Ok(results)
+}
+
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    #[error("failed to read {}: {source}", .path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to write {}: {source}", .path.display())]
+    Write { path: PathBuf, source: io::Error },
+    #[error("line {line}: expected `<day> <phase> <median ns>', got `{text}'")]
+    Malformed { line: usize, text: String },
+}
+
+// median per day and phase from an earlier run, regressions are measured against it
+#[derive(Debug, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Phase), Duration>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let text = fs::read_to_string(path).map_err(|source| BaselineError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Baseline::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, BaselineError> {
+        let mut baseline = Baseline::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let malformed = || BaselineError::Malformed {
+                line: i + 1,
+                text: line.to_string(),
+            };
+            let fields = trimmed.split_whitespace().collect::<Vec<_>>();
+            if fields.len() != 3 {
+                return Err(malformed());
+            }
+            let day = fields[0].parse::<u8>().map_err(|_| malformed())?;
+            let phase = Phase::try_from(fields[1]).map_err(|_| malformed())?;
+            let nanos = fields[2].parse::<u64>().map_err(|_| malformed())?;
+            baseline
+                .medians
+                .insert((day, phase), Duration::from_nanos(nanos));
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        fs::write(path, self.to_string()).map_err(|source| BaselineError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.medians.get(&(day, phase)).copied()
+    }
+
+    pub fn insert(&mut self, day: u8, phase: Phase, median: Duration) {
+        self.medians.insert((day, phase), median);
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# <day> <phase> <median ns>")?;
+        for ((day, phase), median) in self.medians.iter() {
+            writeln!(f, "{} {} {}", day, phase, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+// relative change of the median against the baseline, 0.25 means 25% slower
+pub fn change(baseline: Duration, median: Duration) -> f64 {
+    if baseline.is_zero() {
+        return 0.0;
+    }
+    median.as_secs_f64() / baseline.as_secs_f64() - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_day;
+
+    #[test]
+    fn it_works() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        let day = get_day(1).unwrap();
+        let lines = day.example(None).unwrap().lines();
+        let results = bench_day(day, &lines, 3).unwrap();
+        let phases = results.iter().map(|(phase, _)| *phase).collect::<Vec<_>>();
+        assert_eq!(
+            phases,
+            vec![
+                Phase::Parse,
+                Phase::Solve(Part::One),
+                Phase::Solve(Part::Two)
+            ]
+        );
+
+        let mut baseline = Baseline::default();
+        baseline.insert(1, Phase::Parse, Duration::from_nanos(1500));
+        baseline.insert(1, Phase::Solve(Part::Two), Duration::from_nanos(2000));
+        let text = baseline.to_string();
+        assert_eq!(
+            text,
+            "# <day> <phase> <median ns>\n1 parse 1500\n1 part2 2000\n"
+        );
+        let baseline = Baseline::parse(&text).unwrap();
+        assert_eq!(
+            baseline.get(1, Phase::Solve(Part::Two)),
+            Some(Duration::from_nanos(2000))
+        );
+        assert_eq!(baseline.get(1, Phase::Solve(Part::One)), None);
+
+        let change = change(Duration::from_millis(4), Duration::from_millis(5));
+        assert!((change - 0.25).abs() < 1e-9);
+    }
+}
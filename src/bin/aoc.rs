@@ -0,0 +1,679 @@
+use aoc_2021::answers::{default_answers_path, Answers, Verdict};
+use aoc_2021::bench::{bench_day, change, Baseline};
+use aoc_2021::day01::sweep;
+use aoc_2021::generate::DEFAULT_SIZE;
+use aoc_2021::input::{read_input, InputSource};
+use aoc_2021::log::{set_filter, Filter, Level};
+use aoc_2021::repl;
+use aoc_2021::report::{run_all, Format, Report};
+use aoc_2021::visualize::{play, recording, write_frames};
+use aoc_2021::{get_day, Day, ParseError, Part, DAYS};
+use std::fs;
+use std::fs::File;
+use std::io::{stdin, stdout, BufWriter, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::{env, panic, thread};
+
+const USAGE: &str =
+    "usage: aoc [-v|-vv|-vvv] <command>, AOC_LOG=<level>,day<n>=<level> for single days
+       aoc run <day> [--part 1|2] [--input <path>|- | --example [name]]
+                   [--visualize [--fps <n> | --frames <path>]] [--dump <path>]
+       aoc run --all [--part 1|2] [--example] [--format json|csv] [--threads <n>]
+       aoc verify [--answers <path>]
+       aoc bench [<day>...] [--runs <n>] [--baseline <path>] [--save <path>] [--threshold <percent>]
+       aoc bench [<day>...] --size <n>[,<n>...] [--seed <n>] [--runs <n>]
+       aoc generate <day> [--size <n>] [--seed <n>]
+       aoc repl <day>
+       aoc check <day> <path>|-
+       aoc sweep [--window <n>]";
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!("{}", USAGE);
+    exit(2)
+}
+
+// where the frames recorded by --visualize go
+enum Visualize {
+    Play { fps: f64 },
+    File(PathBuf),
+}
+
+fn run(args: &[String]) {
+    let mut day = None;
+    let mut part = None;
+    let mut source = None;
+    let mut example = None;
+    let mut all = false;
+    let mut format = None;
+    let mut threads = None;
+    let mut visualize = false;
+    let mut fps = None;
+    let mut frames_path = None;
+    let mut dump_path = None;
+
+    let mut args = args.iter().peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--part needs a value"));
+                match Part::try_from(value.as_str()) {
+                    Ok(p) => part = Some(p),
+                    Err(_) => usage_error(&format!("invalid part `{}'", value)),
+                }
+            }
+            "--input" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--input needs a path or -"));
+                source = Some(InputSource::from(value.as_str()));
+            }
+            "--all" => all = true,
+            "--visualize" => visualize = true,
+            "--fps" => match args.next().map(|value| value.parse::<f64>()) {
+                Some(Ok(n)) if n > 0.0 => fps = Some(n),
+                _ => usage_error("--fps needs a positive number"),
+            },
+            "--dump" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--dump needs a path"));
+                dump_path = Some(PathBuf::from(value));
+            }
+            "--frames" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--frames needs a path"));
+                frames_path = Some(PathBuf::from(value));
+            }
+            "--format" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--format needs a value"));
+                match Format::try_from(value.as_str()) {
+                    Ok(f) => format = Some(f),
+                    Err(_) => usage_error(&format!("invalid format `{}'", value)),
+                }
+            }
+            "--threads" => match args.next().map(|value| value.parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => threads = Some(n),
+                _ => usage_error("--threads needs a positive number"),
+            },
+            "--example" => {
+                // the name is optional, a bare number is still the day if that is missing
+                let name = args.next_if(|next| {
+                    !next.starts_with('-') && (day.is_some() || next.parse::<u8>().is_err())
+                });
+                example = Some(name.cloned());
+            }
+            _ if day.is_none() => match arg.parse::<u8>() {
+                Ok(d) => day = Some(d),
+                Err(_) => usage_error(&format!("invalid day `{}'", arg)),
+            },
+            _ => usage_error(&format!("unexpected argument `{}'", arg)),
+        }
+    }
+
+    if source.is_some() && example.is_some() {
+        usage_error("--input and --example can't be combined")
+    }
+    if !visualize && (fps.is_some() || frames_path.is_some()) {
+        usage_error("--fps and --frames only work with --visualize")
+    }
+    if fps.is_some() && frames_path.is_some() {
+        usage_error("--fps and --frames can't be combined")
+    }
+    if all {
+        if day.is_some() {
+            usage_error("--all can't be combined with a day")
+        }
+        if source.is_some() {
+            usage_error("--all always uses the default input")
+        }
+        if matches!(example, Some(Some(_))) {
+            usage_error("--all can only use the first example of each day")
+        }
+        if visualize || dump_path.is_some() {
+            usage_error("--all can't be visualized or dumped")
+        }
+        let threads = threads.unwrap_or_else(|| {
+            thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        });
+        let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+        run_all_days(
+            &parts,
+            example.is_some(),
+            format.unwrap_or(Format::Json),
+            threads,
+        );
+        return;
+    }
+    if format.is_some() || threads.is_some() {
+        usage_error("--format and --threads only work with --all")
+    }
+
+    let day = day.unwrap_or_else(|| usage_error("missing day"));
+    let solution =
+        get_day(day).unwrap_or_else(|| usage_error(&format!("no solution for day {}", day)));
+
+    let parts = match part {
+        Some(part) if !solution.runner.parts().contains(&part) => {
+            usage_error(&format!("day {} has no part {}", day, part))
+        }
+        Some(part) => vec![part],
+        None => solution.runner.parts().to_vec(),
+    };
+
+    let (lines, example) = match example {
+        Some(name) => {
+            let example = solution
+                .example(name.as_deref())
+                .unwrap_or_else(|| match name {
+                    Some(name) => usage_error(&format!("day {} has no example `{}'", day, name)),
+                    None => usage_error(&format!("day {} has no examples", day)),
+                });
+            (example.lines(), Some(example))
+        }
+        None => match read_input(day, &source.unwrap_or(InputSource::Default)) {
+            Ok(input) => (input.lines, None),
+            Err(e) => {
+                eprintln!("{}", e);
+                exit(1)
+            }
+        },
+    };
+
+    if let Some(path) = dump_path {
+        dump(solution, &lines, &path);
+    }
+
+    let visualize = visualize.then(|| match frames_path {
+        Some(path) => Visualize::File(path),
+        None => Visualize::Play {
+            fps: fps.unwrap_or(10.0),
+        },
+    });
+    let answers = match visualize {
+        Some(visualize) => run_visualized(solution, &lines, &parts, &visualize),
+        None => solution.run(&lines, &parts),
+    };
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1)
+        }
+    };
+    for (part, answer) in answers {
+        match example.and_then(|example| example.expected(part)) {
+            Some(expected) if expected != answer => {
+                println!("{} (expected {})", answer, expected)
+            }
+            _ => println!("{}", answer),
+        }
+    }
+}
+
+// writes the final state image of the day, a path without an extension gets the one of the
+// image and any other than that or .pnm is refused
+fn dump(solution: &Day, lines: &[String], path: &Path) {
+    let image = match solution.dump(lines) {
+        Ok(Some(image)) => image,
+        Ok(None) => usage_error(&format!("day {} has nothing to dump", solution.day)),
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1)
+        }
+    };
+    // .pnm fits all of them
+    let path = match path.extension() {
+        None => path.with_extension(image.extension()),
+        Some(extension) if extension == image.extension() || extension == "pnm" => {
+            path.to_path_buf()
+        }
+        Some(extension) => usage_error(&format!(
+            "day {} dumps a .{} image, not .{}",
+            solution.day,
+            image.extension(),
+            extension.to_string_lossy()
+        )),
+    };
+    if let Err(e) = fs::write(&path, image.to_string()) {
+        eprintln!("can't write {}: {}", path.display(), e);
+        exit(1)
+    }
+}
+
+// records every part on its own so the frames can be told apart, answers are
+// printed after the playback
+fn run_visualized(
+    solution: &Day,
+    lines: &[String],
+    parts: &[Part],
+    visualize: &Visualize,
+) -> Result<Vec<(Part, String)>, ParseError> {
+    let mut answers = vec![];
+    let mut frames = vec![];
+    for part in parts {
+        let (answer, part_frames) = recording(|| solution.run(lines, &[*part]));
+        answers.extend(answer?);
+        frames.extend(part_frames.into_iter().map(|mut frame| {
+            frame.title = format!("part {} {}", part, frame.title);
+            frame
+        }));
+    }
+    if frames.is_empty() {
+        eprintln!("day {} records no frames", solution.day);
+    }
+
+    let result = match visualize {
+        Visualize::Play { fps } => play(&frames, *fps, &mut stdout()),
+        Visualize::File(path) => File::create(path).and_then(|file| {
+            let mut out = BufWriter::new(file);
+            write_frames(&frames, &mut out)?;
+            out.flush()
+        }),
+    };
+    if let Err(e) = result {
+        eprintln!("can't write frames: {}", e);
+        exit(1)
+    }
+    Ok(answers)
+}
+
+// every day at once for dashboards, failures are reported per day instead of aborting
+fn run_all_days(parts: &[Part], example: bool, format: Format, threads: usize) {
+    let load = |day: &Day| {
+        if example {
+            day.example(None)
+                .map(|example| example.lines())
+                .ok_or_else(|| format!("day {} has no examples", day.day))
+        } else {
+            read_input(day.day, &InputSource::Default)
+                .map(|input| input.lines)
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    // panics end up in the report, the default hook would only clutter stderr
+    panic::set_hook(Box::new(|_| {}));
+    let days = DAYS.iter().collect::<Vec<_>>();
+    let records = run_all(&days, parts, threads, load);
+    let _ = panic::take_hook();
+
+    print!(
+        "{}",
+        Report {
+            format,
+            records: &records
+        }
+    );
+    if records.iter().any(|record| record.status.is_failure()) {
+        exit(1)
+    }
+}
+
+// runs every day on its default input and compares against the answers file
+fn verify(args: &[String]) {
+    let mut answers_path = default_answers_path();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                let value = args
+                    .next()
+                    .unwrap_or_else(|| usage_error("--answers needs a path"));
+                answers_path = PathBuf::from(value);
+            }
+            _ => usage_error(&format!("unexpected argument `{}'", arg)),
+        }
+    }
+
+    let answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1)
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for solution in DAYS.iter() {
+        let input = match read_input(solution.day, &InputSource::Default) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("day {:2}: missing ({})", solution.day, e);
+                missing += solution.runner.parts().len();
+                continue;
+            }
+        };
+
+        let results = match solution.run(&input.lines, solution.runner.parts()) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("day {:2}: fail ({})", solution.day, e);
+                failed += solution.runner.parts().len();
+                continue;
+            }
+        };
+        for (part, answer) in results {
+            let prefix = format!("day {:2} part {}", solution.day, part);
+            match answers.check(solution.day, part, &input.name, &answer) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("{}: pass", prefix);
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    println!("{}: fail (expected {}, got {})", prefix, expected, answer);
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!(
+                        "{}: missing (no answer for {}, got {})",
+                        prefix, input.name, answer
+                    );
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        exit(1)
+    }
+}
+
+// times every phase of the given days, all days if none are given
+fn bench(args: &[String]) {
+    let mut days = vec![];
+    let mut runs = 10;
+    let mut baseline_path = None;
+    let mut save_path = None;
+    let mut threshold = 20.0;
+    let mut sizes = vec![];
+    let mut seed = 0;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .unwrap_or_else(|| usage_error(&format!("{} needs a value", name)))
+                .to_string()
+        };
+        match arg.as_str() {
+            "--runs" => match value("--runs").parse::<usize>() {
+                Ok(n) if n > 0 => runs = n,
+                _ => usage_error("--runs needs a positive number"),
+            },
+            "--baseline" => baseline_path = Some(PathBuf::from(value("--baseline"))),
+            "--save" => save_path = Some(PathBuf::from(value("--save"))),
+            "--threshold" => match value("--threshold").parse::<f64>() {
+                Ok(t) if t >= 0.0 => threshold = t,
+                _ => usage_error("--threshold needs a percentage"),
+            },
+            "--size" => {
+                for size in value("--size").split(',') {
+                    match size.parse::<usize>() {
+                        Ok(n) => sizes.push(n),
+                        Err(_) => usage_error(&format!("invalid size `{}'", size)),
+                    }
+                }
+            }
+            "--seed" => match value("--seed").parse::<u64>() {
+                Ok(n) => seed = n,
+                Err(_) => usage_error("--seed needs a number"),
+            },
+            _ => match arg.parse::<u8>().ok().and_then(get_day) {
+                Some(day) => days.push(day),
+                None => usage_error(&format!("no solution for day `{}'", arg)),
+            },
+        }
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+    // medians of generated inputs aren't comparable with the ones of the real input
+    if !sizes.is_empty() && (baseline_path.is_some() || save_path.is_some()) {
+        usage_error("--size can't be combined with --baseline or --save")
+    }
+
+    let baseline = baseline_path.map(|path| {
+        Baseline::load(&path).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(1)
+        })
+    });
+
+    let mut measured = Baseline::default();
+    let mut regressions = 0;
+    for solution in days {
+        // the real input or one generated input per size, labeled with the size
+        let inputs = if sizes.is_empty() {
+            match read_input(solution.day, &InputSource::Default) {
+                Ok(input) => vec![(String::new(), input.lines)],
+                Err(e) => {
+                    println!("day {:2}: skipped ({})", solution.day, e);
+                    continue;
+                }
+            }
+        } else {
+            let generated = sizes
+                .iter()
+                .map(|size| {
+                    let lines = solution.generate(*size, seed)?;
+                    Some((format!(" size {}", size), lines))
+                })
+                .collect::<Option<Vec<_>>>();
+            match generated {
+                Some(inputs) => inputs,
+                None => {
+                    println!("day {:2}: skipped (no generator)", solution.day);
+                    continue;
+                }
+            }
+        };
+
+        for (label, lines) in inputs {
+            let results = match bench_day(solution, &lines, runs) {
+                Ok(results) => results,
+                Err(e) => {
+                    println!("day {:2}{}: skipped ({})", solution.day, label, e);
+                    continue;
+                }
+            };
+
+            for (phase, stats) in results {
+                measured.insert(solution.day, phase, stats.median);
+
+                let mut line = format!(
+                    "day {:2}{} {:<5}  min {:>10.3?}  median {:>10.3?}  max {:>10.3?}",
+                    solution.day, label, phase, stats.min, stats.median, stats.max
+                );
+                if let Some(before) = baseline.as_ref().and_then(|b| b.get(solution.day, phase)) {
+                    let percent = change(before, stats.median) * 100.0;
+                    line += &format!("  baseline {:>10.3?} {:+.1}%", before, percent);
+                    if percent > threshold {
+                        regressions += 1;
+                        line += " REGRESSION";
+                    }
+                }
+                println!("{}", line);
+            }
+        }
+    }
+
+    if let Some(path) = save_path {
+        if let Err(e) = measured.save(&path) {
+            eprintln!("{}", e);
+            exit(1)
+        }
+    }
+    if regressions > 0 {
+        println!("{} regressions over {}%", regressions, threshold);
+        exit(1)
+    }
+}
+
+// prints a generated input, pipe it into run --input - to try sizes the real input doesn't have
+fn generate(args: &[String]) {
+    let mut day = None;
+    let mut size = DEFAULT_SIZE;
+    let mut seed = 0;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .unwrap_or_else(|| usage_error(&format!("{} needs a value", name)))
+                .to_string()
+        };
+        match arg.as_str() {
+            "--size" => match value("--size").parse::<usize>() {
+                Ok(n) => size = n,
+                _ => usage_error("--size needs a number"),
+            },
+            "--seed" => match value("--seed").parse::<u64>() {
+                Ok(n) => seed = n,
+                Err(_) => usage_error("--seed needs a number"),
+            },
+            _ if day.is_none() => match arg.parse::<u8>() {
+                Ok(d) => day = Some(d),
+                Err(_) => usage_error(&format!("invalid day `{}'", arg)),
+            },
+            _ => usage_error(&format!("unexpected argument `{}'", arg)),
+        }
+    }
+
+    let day = day.unwrap_or_else(|| usage_error("missing day"));
+    let solution =
+        get_day(day).unwrap_or_else(|| usage_error(&format!("no solution for day {}", day)));
+    let lines = solution
+        .generate(size, seed)
+        .unwrap_or_else(|| usage_error(&format!("day {} has no generator", day)));
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+// reads single lines of the day's format from stdin until it ends, the prompt is only shown
+// on a terminal so sessions can be piped in
+fn repl(args: &[String]) {
+    let day = match args {
+        [day] => day
+            .parse::<u8>()
+            .unwrap_or_else(|_| usage_error(&format!("invalid day `{}'", day))),
+        [] => usage_error("missing day"),
+        [_, arg, ..] => usage_error(&format!("unexpected argument `{}'", arg)),
+    };
+    let solution =
+        get_day(day).unwrap_or_else(|| usage_error(&format!("no solution for day {}", day)));
+    let mut session = solution
+        .repl()
+        .unwrap_or_else(|| usage_error(&format!("day {} has no repl", day)));
+
+    let prompt = stdin().is_terminal();
+    if let Err(e) = repl::run(day, session.as_mut(), stdin().lock(), &mut stdout(), prompt) {
+        eprintln!("{}", e);
+        exit(1)
+    }
+}
+
+// lists every problem of an input file instead of stopping at the first like run does
+fn check(args: &[String]) {
+    let (day, path) = match args {
+        [day, path] => (day, path),
+        [] => usage_error("missing day"),
+        [_] => usage_error("missing input path"),
+        [_, _, arg, ..] => usage_error(&format!("unexpected argument `{}'", arg)),
+    };
+    let day = day
+        .parse::<u8>()
+        .unwrap_or_else(|_| usage_error(&format!("invalid day `{}'", day)));
+    let solution =
+        get_day(day).unwrap_or_else(|| usage_error(&format!("no solution for day {}", day)));
+
+    let input = match read_input(day, &InputSource::from(path.as_str())) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1)
+        }
+    };
+    let problems = solution.check(&input.lines);
+    for problem in problems.iter() {
+        println!("{}", problem);
+    }
+    match problems.len() {
+        0 => println!("{}: no problems", input.name),
+        1 => println!("{}: 1 problem", input.name),
+        n => println!("{}: {} problems", input.name, n),
+    }
+    if !problems.is_empty() {
+        exit(1)
+    }
+}
+
+// day 1 on depths from stdin as they come in, prints the line of every depth that makes its
+// window deeper so the stream doesn't have to end
+fn sweep_stdin(args: &[String]) {
+    let mut window = 1;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" => match args.next().map(|value| value.parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => window = n,
+                _ => usage_error("--window needs a number of at least 1"),
+            },
+            _ => usage_error(&format!("unexpected argument `{}'", arg)),
+        }
+    }
+
+    match sweep(stdin().lock(), window, |index| {
+        println!("line {}", index + 1)
+    }) {
+        Ok(count) => println!("{} increases", count),
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1)
+        }
+    }
+}
+
+// AOC_LOG sets the levels, every v of -v raises the level of all days a step
+fn init_log(verbosity: usize) {
+    let mut filter = match env::var("AOC_LOG") {
+        Ok(spec) => Filter::parse(&spec).unwrap_or_else(|e| usage_error(&e)),
+        Err(_) => Filter::default(),
+    };
+    if verbosity > 0 {
+        filter.level = filter.level.max(Level::from_verbosity(verbosity));
+    }
+    set_filter(filter);
+}
+
+fn main() {
+    let (verbosity, args): (Vec<_>, Vec<_>) = env::args().skip(1).partition(|arg| {
+        arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v')
+    });
+    init_log(verbosity.iter().map(|arg| arg.len() - 1).sum());
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("repl") => repl(&args[1..]),
+        Some("check") => check(&args[1..]),
+        Some("sweep") => sweep_stdin(&args[1..]),
+        Some(command) => usage_error(&format!("unknown command `{}'", command)),
+        None => usage_error("missing command"),
+    }
+}
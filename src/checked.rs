@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+// what the days with huge answers count in, overflows are errors the parts report instead of
+// panics in debug and wrong numbers in release, with the checked feature the type is wider
+#[cfg(not(feature = "checked"))]
+pub type Count = u64;
+#[cfg(feature = "checked")]
+pub type Count = u128;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Error)]
+#[error("`{expression}' overflows {}", std::any::type_name::<Count>())]
+pub struct Overflow {
+    pub expression: String,
+}
+
+pub fn add(lhs: Count, rhs: Count) -> Result<Count, Overflow> {
+    lhs.checked_add(rhs).ok_or_else(|| Overflow {
+        expression: format!("{} + {}", lhs, rhs),
+    })
+}
+
+pub fn mul(lhs: Count, rhs: Count) -> Result<Count, Overflow> {
+    lhs.checked_mul(rhs).ok_or_else(|| Overflow {
+        expression: format!("{} * {}", lhs, rhs),
+    })
+}
+
+pub fn sum(values: impl IntoIterator<Item = Count>) -> Result<Count, Overflow> {
+    values.into_iter().try_fold(0, add)
+}
+
+// a number written in binary, too many bits overflow
+pub fn from_bits(bits: &str) -> Result<Count, Overflow> {
+    Count::from_str_radix(bits, 2).map_err(|_| Overflow {
+        expression: format!("0b{}", bits),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(add(2, 3), Ok(5));
+        assert_eq!(mul(2, 3), Ok(6));
+        assert_eq!(sum([1, 2, 3]), Ok(6));
+        assert_eq!(from_bits("101"), Ok(5));
+
+        let too_long = "1".repeat(Count::BITS as usize + 1);
+        assert!(from_bits(&too_long).is_err());
+
+        assert_eq!(
+            mul(Count::MAX, 2).unwrap_err().to_string(),
+            format!(
+                "`{} * 2' overflows {}",
+                Count::MAX,
+                std::any::type_name::<Count>()
+            )
+        );
+        assert!(add(Count::MAX, 1).is_err());
+        assert!(sum([Count::MAX, 1]).is_err());
+    }
+}
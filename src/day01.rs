@@ -0,0 +1,181 @@
+//! Day 1: Sonar Sweep
+
+use crate::parse::{input_lines, Line};
+use crate::{Example, ParseError, Solution};
+use std::collections::VecDeque;
+use std::io;
+use std::io::BufRead;
+use thiserror::Error;
+
+pub struct Day01;
+
+/// Indices of the depths that make their window of `window` depths deeper than the window
+/// before, see `increases`.
+pub struct Increases<I> {
+    depths: I,
+    // the last window depths, only the one leaving the window matters
+    window: VecDeque<u32>,
+    size: usize,
+    index: usize,
+}
+
+impl<I: Iterator<Item = u32>> Iterator for Increases<I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        for depth in self.depths.by_ref() {
+            let index = self.index;
+            self.index += 1;
+            self.window.push_back(depth);
+            if self.window.len() <= self.size {
+                continue;
+            }
+            // both windows share all depths but the one leaving and the one coming in
+            let leaving = self.window.pop_front().unwrap();
+            if depth > leaving {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+/// Sliding windows of `window` depths compared with the window before, keeps only the last
+/// `window` depths so `depths` can be endless.
+pub fn increases<I: IntoIterator<Item = u32>>(depths: I, window: usize) -> Increases<I::IntoIter> {
+    assert!(window > 0, "a window needs at least one depth");
+    Increases {
+        depths: depths.into_iter(),
+        window: VecDeque::with_capacity(window + 1),
+        size: window,
+        index: 0,
+    }
+}
+
+/// Why a sweep stopped before the end of its reader.
+#[derive(Debug, Error)]
+pub enum SweepError {
+    #[error("day 1 line {line}: {source}")]
+    Read { line: usize, source: io::Error },
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+/// Reads depths line by line from `reader` and calls `increase` with every index from
+/// `increases`, returns how many there were. A line that can't be read or isn't a depth ends
+/// the sweep with an error after the increases before it.
+pub fn sweep(
+    reader: impl BufRead,
+    window: usize,
+    mut increase: impl FnMut(usize),
+) -> Result<usize, SweepError> {
+    let mut error = None;
+    let depths = reader.lines().enumerate().map_while(|(index, text)| {
+        let text = text
+            .map_err(|source| {
+                error = Some(SweepError::Read {
+                    line: index + 1,
+                    source,
+                })
+            })
+            .ok()?;
+        let line = Line {
+            day: Day01::DAY,
+            section: None,
+            index,
+            text: &text,
+        };
+        line.parse(line.text)
+            .map_err(|e| error = Some(e.into()))
+            .ok()
+    });
+
+    let mut count = 0;
+    for index in increases(depths, window) {
+        increase(index);
+        count += 1;
+    }
+    match error {
+        Some(e) => Err(e),
+        None => Ok(count),
+    }
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263",
+    part1: Some("7"),
+    part2: Some("5"),
+}];
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: &[String]) -> Result<Vec<u32>, ParseError> {
+        input_lines(Self::DAY, lines)
+            .map(|line| line.parse(line.text))
+            .collect()
+    }
+
+    fn part1(depths: &Vec<u32>) -> usize {
+        increases(depths.iter().copied(), 1).count()
+    }
+
+    fn part2(depths: &Vec<u32>) -> usize {
+        increases(depths.iter().copied(), 3).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day01>();
+
+        let depths = Day01::parse(&EXAMPLES[0].lines()).unwrap();
+        let indices = increases(depths.iter().copied(), 1).collect::<Vec<_>>();
+        assert_eq!(indices, vec![1, 2, 3, 5, 6, 7, 9]);
+        let indices = increases(depths.iter().copied(), 3).collect::<Vec<_>>();
+        assert_eq!(indices, vec![3, 6, 7, 8, 9]);
+        assert_eq!(increases(depths.iter().copied(), 10).count(), 0);
+
+        // a depth of 0 is compared like any other
+        assert_eq!(increases([0, 1, 0, 0], 1).collect::<Vec<_>>(), vec![1]);
+
+        let mut indices = vec![];
+        let count = sweep(EXAMPLES[0].input.as_bytes(), 3, |i| indices.push(i)).unwrap();
+        assert_eq!((count, indices), (5, vec![3, 6, 7, 8, 9]));
+        let err = sweep("1\n2\nx\n3\n".as_bytes(), 1, |_| {}).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1 line 3 column 1: invalid digit found in string `x'"
+        );
+        let mut indices = vec![];
+        let err = sweep(&b"1\n2\n\xff\n3\n"[..], 1, |i| indices.push(i)).unwrap_err();
+        assert_eq!(
+            (err.to_string(), indices),
+            (
+                "day 1 line 3: stream did not contain valid UTF-8".to_string(),
+                vec![1]
+            )
+        );
+    }
+}
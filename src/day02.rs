@@ -0,0 +1,195 @@
+//! Day 2: Dive!
+
+use crate::parse::{input_lines, Line};
+use crate::visualize::record;
+use crate::{Example, ParseError, Solution};
+use std::fmt::{Display, Formatter};
+
+pub struct Day02;
+
+/// A line of the planned course.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+impl Command {
+    // forward 5, the value can't be negative
+    fn parse(line: &Line) -> Result<Command, ParseError> {
+        let (verb, value) = line.split_once(" ")?;
+        let value = i64::from(line.parse::<u32>(value)?);
+        match verb {
+            "forward" => Ok(Command::Forward(value)),
+            "down" => Ok(Command::Down(value)),
+            "up" => Ok(Command::Up(value)),
+            _ => Err(line.error(verb, "unknown command")),
+        }
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Forward(value) => write!(f, "forward {}", value),
+            Command::Down(value) => write!(f, "down {}", value),
+            Command::Up(value) => write!(f, "up {}", value),
+        }
+    }
+}
+
+/// Where the submarine is, depth is negative above the surface.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "horizontal {} depth {}", self.horizontal, self.depth)
+    }
+}
+
+/// How a model of the submarine moves for a command.
+pub trait Navigation {
+    fn apply(&mut self, command: Command);
+    fn position(&self) -> Position;
+}
+
+/// Part 1, down and up change the depth right away.
+#[derive(Debug, Default)]
+pub struct Simple {
+    position: Position,
+}
+
+impl Navigation for Simple {
+    fn apply(&mut self, command: Command) {
+        match command {
+            Command::Forward(value) => self.position.horizontal += value,
+            Command::Down(value) => self.position.depth += value,
+            Command::Up(value) => self.position.depth -= value,
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// Part 2, down and up change the aim and forward dives along it.
+#[derive(Debug, Default)]
+pub struct Aimed {
+    position: Position,
+    aim: i64,
+}
+
+impl Navigation for Aimed {
+    fn apply(&mut self, command: Command) {
+        match command {
+            Command::Forward(value) => {
+                self.position.horizontal += value;
+                self.position.depth += self.aim * value
+            }
+            Command::Down(value) => self.aim += value,
+            Command::Up(value) => self.aim -= value,
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// Where `model` is after all commands, the last point of its [`trajectory`].
+pub fn navigate(model: impl Navigation, commands: &[Command]) -> Position {
+    *trajectory(model, commands).last().unwrap()
+}
+
+/// The start and the position after every command, every position after a command is
+/// recorded as a frame.
+pub fn trajectory(mut model: impl Navigation, commands: &[Command]) -> Vec<Position> {
+    let mut positions = vec![model.position()];
+    for command in commands.iter() {
+        model.apply(*command);
+        let position = model.position();
+        record(command, || format!("{}\n", position));
+        positions.push(position);
+    }
+    positions
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2",
+    part1: Some("150"),
+    part2: Some("900"),
+}];
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(lines: &[String]) -> Result<Vec<Command>, ParseError> {
+        input_lines(Self::DAY, lines)
+            .map(|line| Command::parse(&line))
+            .collect()
+    }
+
+    fn part1(commands: &Vec<Command>) -> i64 {
+        let position = navigate(Simple::default(), commands);
+        position.horizontal * position.depth
+    }
+
+    fn part2(commands: &Vec<Command>) -> i64 {
+        let position = navigate(Aimed::default(), commands);
+        position.horizontal * position.depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day02>();
+
+        // up above the surface used to underflow
+        let lines = ["down 2", "up 5", "forward 3"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let commands = Day02::parse(&lines).unwrap();
+        let depths = trajectory(Simple::default(), &commands)
+            .iter()
+            .map(|position| position.depth)
+            .collect::<Vec<_>>();
+        assert_eq!(depths, vec![0, 2, -3, -3]);
+        assert_eq!(
+            navigate(Aimed::default(), &commands),
+            Position {
+                horizontal: 3,
+                depth: -9
+            }
+        );
+
+        let lines = ["forward 1".to_string(), "backward 2".to_string()];
+        assert_eq!(
+            Day02::parse(&lines).unwrap_err().to_string(),
+            "day 2 line 2 column 1: unknown command `backward'"
+        );
+    }
+}
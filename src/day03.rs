@@ -0,0 +1,389 @@
+//! Day 3: Binary Diagnostic
+
+use crate::parse::{input_line, input_lines};
+use crate::{debug, Example, Outcome, ParseError, Solution};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+pub struct Day03;
+
+/// A set of bits packed into 64 bit words, bits past `len` are always 0.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Bits {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bits {
+    pub fn new(len: usize) -> Self {
+        Bits {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    pub fn full(len: usize) -> Self {
+        let mut bits = Bits {
+            words: vec![u64::MAX; len.div_ceil(64)],
+            len,
+        };
+        if !len.is_multiple_of(64) {
+            *bits.words.last_mut().unwrap() = (1 << (len % 64)) - 1;
+        }
+        bits
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    pub fn set(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn first_one(&self) -> Option<usize> {
+        self.words
+            .iter()
+            .position(|word| *word != 0)
+            .map(|i| i * 64 + self.words[i].trailing_zeros() as usize)
+    }
+
+    fn zip(&self, other: &Bits, f: impl Fn(u64, u64) -> u64) -> Bits {
+        Bits {
+            words: self
+                .words
+                .iter()
+                .zip(other.words.iter())
+                .map(|(a, b)| f(*a, *b))
+                .collect(),
+            len: self.len,
+        }
+    }
+
+    pub fn and(&self, other: &Bits) -> Bits {
+        self.zip(other, |a, b| a & b)
+    }
+
+    pub fn and_not(&self, other: &Bits) -> Bits {
+        self.zip(other, |a, b| a & !b)
+    }
+}
+
+/// An unsigned number of any width, rates are as wide as the report.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Wide {
+    // least significant word first, no zero words at the end so equal numbers are equal
+    words: Vec<u64>,
+}
+
+impl Wide {
+    fn normalized(mut words: Vec<u64>) -> Self {
+        while words.last() == Some(&0) {
+            words.pop();
+        }
+        Wide { words }
+    }
+
+    /// From binary digits, the most significant first like in the report.
+    pub fn from_bits(bits: &[bool]) -> Self {
+        let mut words = vec![0; bits.len().div_ceil(64)];
+        for (i, bit) in bits.iter().rev().enumerate() {
+            if *bit {
+                words[i / 64] |= 1 << (i % 64);
+            }
+        }
+        Wide::normalized(words)
+    }
+
+    pub fn mul(&self, other: &Wide) -> Wide {
+        let mut words = vec![0; self.words.len() + other.words.len()];
+        for (i, a) in self.words.iter().enumerate() {
+            // a * b + two words never needs more than 128 bits
+            let mut carry = 0;
+            for (j, b) in other.words.iter().enumerate() {
+                let product = words[i + j] as u128 + *a as u128 * *b as u128 + carry;
+                words[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            words[i + other.words.len()] = carry as u64;
+        }
+        Wide::normalized(words)
+    }
+}
+
+impl From<u64> for Wide {
+    fn from(n: u64) -> Self {
+        Wide::normalized(vec![n])
+    }
+}
+
+impl Display for Wide {
+    // decimal, 19 digits at a time divided off from the lowest end
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+        let mut words = self.words.clone();
+        let mut chunks = vec![];
+        while !words.is_empty() {
+            let mut rest = 0;
+            for word in words.iter_mut().rev() {
+                let value = (rest << 64) | *word as u128;
+                *word = (value / CHUNK) as u64;
+                rest = value % CHUNK;
+            }
+            chunks.push(rest as u64);
+            while words.last() == Some(&0) {
+                words.pop();
+            }
+        }
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, rest)) => {
+                write!(f, "{}", first)?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{:019}", chunk))
+            }
+        }
+    }
+}
+
+/// The report packed by column, bit `n` of column `i` is the `i`th bit from the left of
+/// the `n`th number. Numbers can be any width, all of them the same.
+#[derive(Debug, Clone)]
+pub struct Report {
+    numbers: usize,
+    columns: Vec<Bits>,
+}
+
+impl Report {
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Ones in every column.
+    pub fn popcounts(&self) -> Vec<usize> {
+        self.columns.iter().map(Bits::count_ones).collect()
+    }
+
+    /// The bits of the `n`th number as written in the report.
+    pub fn number(&self, n: usize) -> Vec<bool> {
+        self.columns.iter().map(|column| column.get(n)).collect()
+    }
+}
+
+/// The ratings of part 2, ties keep 1 for oxygen and 0 for CO2.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Rating {
+    Oxygen,
+    Co2,
+}
+
+impl Rating {
+    // true to keep the numbers with a 1 in the column
+    fn keeps_ones(self, ones: usize, zeros: usize) -> bool {
+        match self {
+            Rating::Oxygen => ones >= zeros,
+            Rating::Co2 => ones < zeros,
+        }
+    }
+}
+
+impl Display for Rating {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rating::Oxygen => write!(f, "oxygen generator"),
+            Rating::Co2 => write!(f, "CO2 scrubber"),
+        }
+    }
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum DiagnosticError {
+    #[error("as many 0 as 1 bits in column {column}, there is no most common bit")]
+    Tie { column: usize },
+    #[error("no number is left for the {rating} rating after column {column}")]
+    NoneLeft { rating: Rating, column: usize },
+}
+
+/// Power consumption, gamma rate times epsilon rate.
+pub fn gamma_epsilon(report: &Report) -> Result<Wide, DiagnosticError> {
+    let mut gamma = Vec::with_capacity(report.width());
+    for (column, ones) in report.popcounts().into_iter().enumerate() {
+        let zeros = report.numbers - ones;
+        if ones == zeros {
+            return Err(DiagnosticError::Tie { column });
+        }
+        gamma.push(ones > zeros);
+    }
+    // epsilon takes the least common bit, the other one everywhere
+    let epsilon = gamma.iter().map(|bit| !bit).collect::<Vec<_>>();
+
+    let (gamma, epsilon) = (Wide::from_bits(&gamma), Wide::from_bits(&epsilon));
+    debug!("gamma {} epsilon {}", gamma, epsilon);
+    Ok(gamma.mul(&epsilon))
+}
+
+/// The number left after keeping the numbers with the bit `rating` picks column by column.
+pub fn calc_rate(report: &Report, rating: Rating) -> Result<Wide, DiagnosticError> {
+    let mut left = Bits::full(report.numbers);
+    for (column, bits) in report.columns.iter().enumerate() {
+        let count = left.count_ones();
+        if count == 1 {
+            break;
+        }
+        let with_one = left.and(bits);
+        let ones = with_one.count_ones();
+        if ones * 2 == count {
+            debug!("{} tie in column {}", rating, column);
+        }
+        left = if rating.keeps_ones(ones, count - ones) {
+            with_one
+        } else {
+            left.and_not(bits)
+        };
+        if left.count_ones() == 0 {
+            return Err(DiagnosticError::NoneLeft { rating, column });
+        }
+    }
+
+    // numbers that agree in every column are all the same, any of them is the rating
+    match left.first_one() {
+        Some(index) => Ok(Wide::from_bits(&report.number(index))),
+        None => Err(DiagnosticError::NoneLeft {
+            rating,
+            column: report.columns.len(),
+        }),
+    }
+}
+
+/// Life support rating, oxygen generator rating times CO2 scrubber rating.
+pub fn life_support(report: &Report) -> Result<Wide, DiagnosticError> {
+    let oxygen_rate = calc_rate(report, Rating::Oxygen)?;
+    let co2_scrubbing_rate = calc_rate(report, Rating::Co2)?;
+    Ok(oxygen_rate.mul(&co2_scrubbing_rate))
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010",
+    part1: Some("198"),
+    part2: Some("230"),
+}];
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Report;
+    type Answer1 = Outcome<Wide, DiagnosticError>;
+    type Answer2 = Outcome<Wide, DiagnosticError>;
+
+    fn parse(lines: &[String]) -> Result<Report, ParseError> {
+        let width = input_line(Self::DAY, lines, 0)?.text.len();
+        let mut columns = vec![Bits::new(lines.len()); width];
+        for line in input_lines(Self::DAY, lines) {
+            for (column, c) in line.text.char_indices() {
+                match c {
+                    '1' if column < width => columns[column].set(line.index),
+                    '0' if column < width => {}
+                    '0' | '1' => {
+                        let extra = &line.text[column..column + 1];
+                        return Err(line.error(extra, format!("expected {} bits", width)));
+                    }
+                    _ => {
+                        let c = &line.text[column..column + c.len_utf8()];
+                        return Err(line.error(c, "not a binary digit"));
+                    }
+                }
+            }
+            if line.text.len() < width {
+                return Err(line.error(line.end(), format!("expected {} bits", width)));
+            }
+        }
+        Ok(Report {
+            numbers: lines.len(),
+            columns,
+        })
+    }
+
+    fn part1(report: &Report) -> Outcome<Wide, DiagnosticError> {
+        gamma_epsilon(report).into()
+    }
+
+    fn part2(report: &Report) -> Outcome<Wide, DiagnosticError> {
+        life_support(report).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    fn report(numbers: &[&str]) -> Report {
+        let lines = numbers.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        Day03::parse(&lines).unwrap()
+    }
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day03>();
+
+        let example = Day03::parse(&EXAMPLES[0].lines()).unwrap();
+        assert_eq!(example.popcounts(), vec![7, 5, 8, 7, 5]);
+        assert_eq!(calc_rate(&example, Rating::Oxygen), Ok(Wide::from(23)));
+        assert_eq!(calc_rate(&example, Rating::Co2), Ok(Wide::from(10)));
+
+        // 70 bits wide, gamma is 1010... and epsilon 0101...
+        let wide = report(&[&"10".repeat(35), &"11".repeat(35), &"00".repeat(35)]);
+        assert_eq!(wide.popcounts()[..4], [2, 1, 2, 1]);
+        assert_eq!(
+            Day03::part1(&wide).to_string(),
+            "309732572201814210298582490844241727003762"
+        );
+        assert_eq!(Wide::from_bits(&[false; 3]).to_string(), "0");
+        let max = Wide::from(u64::MAX);
+        assert_eq!(
+            max.mul(&max).to_string(),
+            "340282366920938463426481119284349108225"
+        );
+
+        let tie = report(&["10", "01"]);
+        assert_eq!(gamma_epsilon(&tie), Err(DiagnosticError::Tie { column: 0 }));
+        assert_eq!(
+            Day03::part1(&tie).to_string(),
+            "no answer: as many 0 as 1 bits in column 0, there is no most common bit"
+        );
+        let same = report(&["11", "11"]);
+        assert_eq!(
+            calc_rate(&same, Rating::Co2).unwrap_err().to_string(),
+            "no number is left for the CO2 scrubber rating after column 0"
+        );
+        assert_eq!(calc_rate(&same, Rating::Oxygen), Ok(Wide::from(3)));
+
+        let lines = ["101".to_string(), "1011".to_string()];
+        assert_eq!(
+            Day03::parse(&lines).unwrap_err().to_string(),
+            "day 3 line 2 column 4: expected 3 bits `1'"
+        );
+    }
+}
@@ -0,0 +1,328 @@
+//! Day 4: Giant Squid
+
+use crate::parse::{sections, Check, Problems, Section};
+use crate::{debug, Example, Outcome, ParseError, Solution};
+use std::collections::HashMap;
+use thiserror::Error;
+
+pub struct Day04;
+
+/// A board of any number of rows and columns, every number on it only once.
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
+    rows: usize,
+    cols: usize,
+    // number -> cell, cells are counted row by row
+    cells: HashMap<u16, usize>,
+    sum: u64,
+}
+
+/// The drawn numbers in order and the boards, a board is named by its index.
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    numbers: Vec<u16>,
+    boards: Vec<BingoBoard>,
+}
+
+/// What counts as a win besides a full row or column. The parts play by the default rules,
+/// [`winners`] and [`timeline`] take any.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Rules {
+    // both diagonals of square boards
+    pub diagonals: bool,
+}
+
+// the marks on a board during a game, counted per row, column and diagonal so a number
+// is marked and checked without looking at the rest of the board
+struct Card<'a> {
+    board: &'a BingoBoard,
+    marked: Vec<bool>,
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    diagonals: [usize; 2],
+    unmarked: u64,
+    won: bool,
+}
+
+impl<'a> Card<'a> {
+    fn new(board: &'a BingoBoard) -> Self {
+        Card {
+            board,
+            marked: vec![false; board.rows * board.cols],
+            rows: vec![0; board.rows],
+            cols: vec![0; board.cols],
+            diagonals: [0; 2],
+            unmarked: board.sum,
+            won: false,
+        }
+    }
+
+    // true if the number completes a line
+    fn mark(&mut self, number: u16, rules: Rules) -> bool {
+        let board = self.board;
+        let Some(&cell) = board.cells.get(&number) else {
+            return false;
+        };
+        if self.marked[cell] {
+            return false;
+        }
+        self.marked[cell] = true;
+        self.unmarked -= number as u64;
+
+        let (row, col) = (cell / board.cols, cell % board.cols);
+        self.rows[row] += 1;
+        self.cols[col] += 1;
+        let mut complete = self.rows[row] == board.cols || self.cols[col] == board.rows;
+        if rules.diagonals && board.rows == board.cols {
+            for (diagonal, on) in [row == col, row + col == board.cols - 1].iter().enumerate() {
+                if *on {
+                    self.diagonals[diagonal] += 1;
+                    complete |= self.diagonals[diagonal] == board.rows;
+                }
+            }
+        }
+        complete
+    }
+}
+
+/// A board completing a line, `draw` counts the drawn numbers from 0.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Win {
+    pub board: usize,
+    pub draw: usize,
+    pub number: u16,
+    // the unmarked numbers of the board times the number
+    pub score: u64,
+}
+
+/// Every board that wins in the order they win, boards winning on the same draw in the order
+/// of the input.
+pub fn timeline(bingo: &Bingo, rules: Rules) -> Vec<Win> {
+    let mut cards = bingo.boards.iter().map(Card::new).collect::<Vec<_>>();
+    let mut wins = vec![];
+    for (draw, number) in bingo.numbers.iter().enumerate() {
+        for (board, card) in cards.iter_mut().enumerate() {
+            if !card.won && card.mark(*number, rules) {
+                card.won = true;
+                let score = card.unmarked * *number as u64;
+                debug!("board {} wins on draw {} with {}", board, draw, score);
+                wins.push(Win {
+                    board,
+                    draw,
+                    number: *number,
+                    score,
+                });
+            }
+        }
+        if wins.len() == cards.len() {
+            break;
+        }
+    }
+    wins
+}
+
+/// No board completes a line before the numbers run out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
+#[error("no board wins with the drawn numbers")]
+pub struct NoWinner;
+
+/// The first and the last board to win, their scores are part 1 and 2 with the default rules.
+pub fn winners(bingo: &Bingo, rules: Rules) -> Result<(Win, Win), NoWinner> {
+    let wins = timeline(bingo, rules);
+    match (wins.first(), wins.last()) {
+        (Some(first), Some(last)) => Ok((*first, *last)),
+        _ => Err(NoWinner),
+    }
+}
+
+// lines of numbers separated by any amount of spaces, all as long as the first
+fn read_board(section: &Section, problems: &mut Problems) -> Option<BingoBoard> {
+    let before = problems.len();
+    let cols = section.first().text.split_ascii_whitespace().count();
+    let mut cells = HashMap::new();
+    let mut sum = 0;
+    for line in section.lines.iter() {
+        let tokens = line.text.split_ascii_whitespace().collect::<Vec<_>>();
+        if tokens.len() != cols {
+            problems.push(line.error(line.text, format!("expected {} numbers in", cols)));
+        }
+        for token in tokens {
+            let Some(number) = problems.take(line.parse::<u16>(token)) else {
+                continue;
+            };
+            if cells.insert(number, cells.len()).is_some() {
+                problems.push(line.error(token, "number already on the board"));
+            }
+            sum += number as u64;
+        }
+    }
+    (problems.len() == before).then_some(BingoBoard {
+        rows: section.lines.len(),
+        cols,
+        cells,
+        sum,
+    })
+}
+
+// the drawn numbers and the boards, goes on after errors
+fn read(lines: &[String], problems: &mut Problems) -> Option<Bingo> {
+    let sections = sections(Day04::DAY, lines);
+    let numbers = problems
+        .take(sections.get(0).and_then(|section| section.single()))
+        .and_then(|line| {
+            line.text
+                .split(',')
+                .map(|token| problems.take(line.parse(token)))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+        });
+
+    // every board is read even after one failed
+    let boards = sections
+        .iter()
+        .skip(1)
+        .map(|section| read_board(section, problems))
+        .collect::<Vec<_>>();
+
+    Some(Bingo {
+        numbers: numbers?,
+        boards: boards.into_iter().collect::<Option<_>>()?,
+    })
+}
+
+fn check(lines: &[String]) -> Vec<ParseError> {
+    Problems::all(|problems| read(lines, problems))
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7",
+    part1: Some("4512"),
+    part2: Some("1924"),
+}];
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const CHECK: Option<Check> = Some(check);
+
+    type Input = Bingo;
+    type Answer1 = Outcome<u64, NoWinner>;
+    type Answer2 = Outcome<u64, NoWinner>;
+
+    fn parse(lines: &[String]) -> Result<Bingo, ParseError> {
+        Problems::first(Self::DAY, |problems| read(lines, problems))
+    }
+
+    fn part1(bingo: &Bingo) -> Outcome<u64, NoWinner> {
+        let winners = winners(bingo, Rules::default());
+        winners.map(|(first, _)| first.score).into()
+    }
+
+    fn part2(bingo: &Bingo) -> Outcome<u64, NoWinner> {
+        let winners = winners(bingo, Rules::default());
+        winners.map(|(_, last)| last.score).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    fn bingo(input: &str) -> Bingo {
+        let lines = input
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        Day04::parse(&lines).unwrap()
+    }
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day04>();
+
+        let wins = timeline(&bingo(EXAMPLES[0].input), Rules::default());
+        assert_eq!(wins.len(), 3);
+        assert_eq!(
+            (wins[0].board, wins[0].number, wins[0].score),
+            (2, 24, 4512)
+        );
+        assert_eq!(
+            (wins[2].board, wins[2].draw, wins[2].number, wins[2].score),
+            (1, 14, 13, 1924)
+        );
+
+        // 2x3, the column is complete after two numbers
+        let wide = bingo("3,6\n\n1 2 3\n4 5 6");
+        assert_eq!(timeline(&wide, Rules::default())[0].score, 72);
+
+        // 20 rows of 15 large numbers, the score doesn't fit 32 bits
+        let rows = (0..20)
+            .map(|row| {
+                let numbers = (0..15).map(|col| (60000 + row * 15 + col).to_string());
+                numbers.collect::<Vec<_>>().join(" ")
+            })
+            .collect::<Vec<_>>();
+        let draws = (60000..60015).map(|n| n.to_string()).collect::<Vec<_>>();
+        let large = bingo(&format!("{}\n\n{}", draws.join(","), rows.join("\n")));
+        assert_eq!(Day04::part1(&large), Outcome(Ok(1028924726430)));
+
+        let square = bingo("1,4\n\n1 2\n3 4");
+        assert!(timeline(&square, Rules::default()).is_empty());
+        assert_eq!(
+            Day04::part2(&square).to_string(),
+            "no answer: no board wins with the drawn numbers"
+        );
+        let diagonals = Rules { diagonals: true };
+        assert_eq!(
+            timeline(&square, diagonals),
+            vec![Win {
+                board: 0,
+                draw: 1,
+                number: 4,
+                score: 20
+            }]
+        );
+
+        let lines = "7,x,9\n\n1 2 3\n4 5 y 6\n7 8 9\n\n1 2\n3 1"
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        let problems = check(&lines)
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            problems,
+            vec![
+                "day 4 section 1 line 1 column 3: invalid digit found in string `x'",
+                "day 4 section 2 line 4 column 1: expected 3 numbers in `4 5 y 6'",
+                "day 4 section 2 line 4 column 5: invalid digit found in string `y'",
+                "day 4 section 3 line 8 column 3: number already on the board `1'",
+            ]
+        );
+        let first = Day04::parse(&lines).err().map(|e| e.to_string());
+        assert_eq!(first.as_ref(), problems.first());
+    }
+}
@@ -0,0 +1,289 @@
+//! Day 5: Hydrothermal Venture
+
+use crate::generate::{Generator, Rng};
+use crate::parse::{input_lines, Check, Line, Problems};
+use crate::{trace, Example, ParseError, Solution};
+use std::cmp::{max, min};
+use std::collections::HashMap;
+
+pub struct Day05;
+
+#[derive(Debug)]
+pub struct Segment {
+    x_start: u16,
+    y_start: u16,
+    x_end: u16,
+    y_end: u16,
+    diagonal: bool,
+}
+
+impl Segment {
+    #[allow(dead_code)]
+    fn get_max_x(&self) -> u16 {
+        max(self.x_start, self.x_end)
+    }
+    #[allow(dead_code)]
+    fn get_min_x(&self) -> u16 {
+        min(self.x_start, self.x_end)
+    }
+
+    #[allow(dead_code)]
+    fn get_max_y(&self) -> u16 {
+        max(self.y_start, self.y_end)
+    }
+    #[allow(dead_code)]
+    fn get_min_y(&self) -> u16 {
+        min(self.y_start, self.y_end)
+    }
+
+    fn insert_into_grid(&self, grid: &mut HashMap<(u16, u16), usize>) {
+        if !self.diagonal {
+            if self.y_start == self.y_end {
+                // line left to right
+                if self.x_start < self.x_end {
+                    for x in self.x_start..self.x_end + 1 {
+                        let pos = (x, self.y_start);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    }
+                } else {
+                    // line right to left
+                    for x in (self.x_end..self.x_start + 1).rev() {
+                        let pos = (x, self.y_start);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    }
+                }
+            }
+            if self.x_start == self.x_end {
+                // col up to down
+                if self.y_start < self.y_end {
+                    for y in self.y_start..self.y_end + 1 {
+                        let pos = (self.x_start, y);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    }
+                } else {
+                    // col down to up
+                    for y in (self.y_end..self.y_start + 1).rev() {
+                        let pos = (self.x_start, y);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    }
+                }
+            }
+        } else {
+            if self.y_start < self.y_end {
+                // diagonal left to right
+                for y in self.y_start..self.y_end + 1 {
+                    let diff = y - self.y_start;
+                    if self.x_start < self.x_end {
+                        // left to right down
+                        let pos = (self.x_start + diff, y);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    } else {
+                        // left to right up
+                        let pos = (self.x_start - diff, y);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    }
+                }
+            } else {
+                // diagonal right to left
+                for y in (self.y_end..self.y_start + 1).rev() {
+                    let diff = self.y_start - y;
+                    if self.x_start < self.x_end {
+                        // left to right down
+                        let pos = (self.x_start + diff, y);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    } else {
+                        // left to right up
+                        let pos = (self.x_start - diff, y);
+                        grid.entry(pos).and_modify(|n| *n += 1).or_insert(1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// only the part of the floor that has vents
+fn render_grid(grid: &HashMap<(u16, u16), usize>) -> String {
+    let min_x = grid.keys().map(|pos| pos.0).min().unwrap_or(0);
+    let max_x = grid.keys().map(|pos| pos.0).max().unwrap_or(0);
+    let min_y = grid.keys().map(|pos| pos.1).min().unwrap_or(0);
+    let max_y = grid.keys().map(|pos| pos.1).max().unwrap_or(0);
+
+    let mut s = String::new();
+    for y in min_y..max_y + 1 {
+        for x in min_x..max_x + 1 {
+            match grid.get(&(x, y)) {
+                Some(count) => s += &count.to_string(),
+                None => s.push('.'),
+            }
+        }
+        s.push('\n');
+    }
+    s
+}
+
+fn count_overlaps(grid: &HashMap<(u16, u16), usize>) -> usize {
+    grid.iter().filter(|e| e.1 > &1).count()
+}
+
+// size segments on a 1000x1000 floor, horizontal, vertical or diagonal like the real input
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let directions = [
+        (1, 0),
+        (-1, 0),
+        (0, 1),
+        (0, -1),
+        (1, 1),
+        (1, -1),
+        (-1, 1),
+        (-1, -1),
+    ];
+    let on_floor = |v: i64| (0..=999).contains(&v);
+    (0..size)
+        .map(|_| {
+            let (x, y) = (rng.range(0..=999), rng.range(0..=999));
+            let (dx, dy) = loop {
+                let (dx, dy) = *rng.pick(&directions);
+                if on_floor(x + dx) && on_floor(y + dy) {
+                    break (dx, dy);
+                }
+            };
+            // shortened until the end is on the floor too
+            let mut length = rng.range(1..=300);
+            while !on_floor(x + dx * length) || !on_floor(y + dy * length) {
+                length -= 1;
+            }
+            format!("{},{} -> {},{}", x, y, x + dx * length, y + dy * length)
+        })
+        .collect()
+}
+
+// x1,y1 -> x2,y2 where the line is horizontal, vertical or at 45 degrees
+fn read_segment(line: &Line, problems: &mut Problems) -> Option<Segment> {
+    let (start_coords, end_coords) = problems.take(line.split_once(" -> "))?;
+    let mut point = |coords: &str| {
+        let Some((x, y)) = coords.split_once(',') else {
+            problems.push(line.error(coords, "expected x,y"));
+            return None;
+        };
+        let x = problems.take(line.parse::<u16>(x));
+        let y = problems.take(line.parse::<u16>(y));
+        Some((x?, y?))
+    };
+    let start = point(start_coords);
+    let end = point(end_coords);
+    let (start, end) = (start?, end?);
+
+    let straight = start.0 == end.0 || start.1 == end.1;
+    if !straight && start.0.abs_diff(end.0) != start.1.abs_diff(end.1) {
+        problems.push(line.error(line.text, "expected a line at 45 degrees, got"));
+        return None;
+    }
+    Some(Segment {
+        x_start: start.0,
+        y_start: start.1,
+        x_end: end.0,
+        y_end: end.1,
+        diagonal: !straight,
+    })
+}
+
+// every line is read even after one failed
+fn read(lines: &[String], problems: &mut Problems) -> Option<Vec<Segment>> {
+    input_lines(Day05::DAY, lines)
+        .map(|line| read_segment(&line, problems))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn check(lines: &[String]) -> Vec<ParseError> {
+    Problems::all(|problems| read(lines, problems))
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2",
+    part1: Some("5"),
+    part2: Some("12"),
+}];
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const GENERATOR: Option<Generator> = Some(generate);
+    const CHECK: Option<Check> = Some(check);
+
+    type Input = Vec<Segment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: &[String]) -> Result<Vec<Segment>, ParseError> {
+        Problems::first(Self::DAY, |problems| read(lines, problems))
+    }
+
+    fn part1(segments: &Vec<Segment>) -> usize {
+        let mut grid: HashMap<(u16, u16), usize> = HashMap::new();
+        for s in segments.iter().filter(|s| !s.diagonal) {
+            s.insert_into_grid(&mut grid);
+        }
+        trace!("vents\n{}", render_grid(&grid));
+        count_overlaps(&grid)
+    }
+
+    fn part2(segments: &Vec<Segment>) -> usize {
+        let mut grid: HashMap<(u16, u16), usize> = HashMap::new();
+
+        for s in segments.iter() {
+            s.insert_into_grid(&mut grid);
+        }
+        trace!("vents\n{}", render_grid(&grid));
+        count_overlaps(&grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day05>();
+
+        let lines = [
+            "0,9 -> 5,9",
+            "8,0 => 0,8",
+            "x,4 -> 3,y",
+            "2,2 -> 4,3",
+            "7 -> 7,4",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+        let problems = check(&lines)
+            .iter()
+            .map(|e| (e.line, e.column, e.message.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            problems,
+            vec![
+                (2, 1, "missing ` -> ' in".to_string()),
+                (3, 1, "invalid digit found in string".to_string()),
+                (3, 10, "invalid digit found in string".to_string()),
+                (4, 1, "expected a line at 45 degrees, got".to_string()),
+                (5, 1, "expected x,y".to_string()),
+            ]
+        );
+    }
+}
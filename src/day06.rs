@@ -0,0 +1,146 @@
+//! Day 6: Lanternfish
+
+use crate::checked::{add, sum, Count, Overflow};
+use crate::parse::input_line;
+use crate::simulate::{simulate, Stop};
+use crate::{Example, Outcome, ParseError, Solution};
+
+pub struct Day06;
+
+// obviously those was a dead end as soon as it
+// was known to be exponential
+/// Number of fish after `days`, simulating every fish on its own.
+pub fn simulate_list(init_fish: &[usize], days: u16) -> usize {
+    let mut fish = init_fish.iter().map(|f| *f as u16).collect::<Vec<u16>>();
+
+    for _ in 0..days {
+        let mut new_fish = vec![];
+        let mut new_fish_num = 0;
+        for f in fish.iter() {
+            match f {
+                0 => {
+                    new_fish.push(6);
+                    new_fish_num += 1;
+                }
+                _ => new_fish.push(f - 1),
+            }
+        }
+        fish.clear();
+        fish.append(&mut new_fish);
+        (0..new_fish_num).for_each(|_| fish.push(8));
+    }
+    fish.len()
+}
+
+// we can just keep track of the num of fishes no need
+// to store all of them in a long list
+// every day the numbers flow down
+/// Number of fish after `days`, counting fish per timer value.
+pub fn simulate_buckets(init_fish: &[usize], days: usize) -> Result<Count, Overflow> {
+    // age -> number of fish with that age
+    let mut fish_map: [Count; 9] = [0; 9];
+    for age in init_fish.iter() {
+        fish_map[*age] += 1;
+    }
+
+    let fish = simulate(
+        Ok(fish_map),
+        // an overflow maps to itself and ends the simulation as a fixpoint
+        |fish_map: &Result<[Count; 9], Overflow>| {
+            let fish_map = fish_map.as_ref().map_err(Overflow::clone)?;
+            // move all numbers above 0 one down
+            // 8 is then empty
+            let mut next = [0; 9];
+            next[..8].copy_from_slice(&fish_map[1..]);
+
+            // all old 0 switch to 6 and add the same number to 8
+            // MUST use += here cause we can have fish
+            // from age 7 already in here from before
+            next[6] = add(next[6], fish_map[0])?;
+            next[8] = fish_map[0];
+            Ok(next)
+        },
+        Stop::After(days),
+    );
+    sum(fish.state?)
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "3,4,3,1,2",
+    part1: Some("5934"),
+    part2: Some("26984457539"),
+}];
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<usize>;
+    type Answer1 = Outcome<Count, Overflow>;
+    type Answer2 = Outcome<Count, Overflow>;
+
+    fn parse(lines: &[String]) -> Result<Vec<usize>, ParseError> {
+        let line = input_line(Self::DAY, lines, 0)?;
+        line.text
+            .split(',')
+            .map(|token| {
+                let age = line.parse::<usize>(token)?;
+                if age > 8 {
+                    return Err(line.error(token, "expected an age from 0 to 8"));
+                }
+                Ok(age)
+            })
+            .collect()
+    }
+
+    fn part1(fish: &Vec<usize>) -> Outcome<Count, Overflow> {
+        simulate_buckets(fish, 80).into()
+    }
+
+    fn part2(fish: &Vec<usize>) -> Outcome<Count, Overflow> {
+        simulate_buckets(fish, 256).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+    use crate::property::{check_agree, shrink_int, shrink_vec};
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day06>();
+
+        // the list grows exponentially, a few fish for a few weeks is all it can do
+        check_agree(
+            100,
+            |rng| {
+                let fish = (0..rng.below(10)).map(|_| rng.below(9)).collect::<Vec<_>>();
+                (fish, rng.below(81))
+            },
+            |(fish, days)| {
+                let fewer_fish = shrink_vec(fish, |t| {
+                    shrink_int(*t as i64)
+                        .into_iter()
+                        .map(|t| t as usize)
+                        .collect()
+                })
+                .into_iter()
+                .map(|fish| (fish, *days));
+                let fewer_days = shrink_int(*days as i64)
+                    .into_iter()
+                    .map(|days| (fish.clone(), days as usize));
+                fewer_fish.chain(fewer_days).collect()
+            },
+            |(fish, days)| Ok(simulate_list(fish, *days as u16) as Count),
+            |(fish, days)| simulate_buckets(fish, *days),
+        );
+
+        // the school grows about 9% a day, u128 runs out after about 1000 days
+        let fish = Day06::parse(&EXAMPLES[0].lines()).unwrap();
+        let overflow = Outcome::from(simulate_buckets(&fish, 2000)).to_string();
+        assert!(overflow.starts_with("no answer: `"), "{}", overflow);
+    }
+}
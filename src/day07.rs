@@ -0,0 +1,80 @@
+//! Day 7: The Treachery of Whales
+
+use crate::parse::input_line;
+use crate::{Example, ParseError, Solution};
+
+pub struct Day07;
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "16,1,2,0,4,2,7,1,2,14",
+    part1: Some("37"),
+    part2: Some("168"),
+}];
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: &[String]) -> Result<Vec<u32>, ParseError> {
+        let line = input_line(Self::DAY, lines, 0)?;
+        line.parse_all(line.text.split(','))
+    }
+
+    fn part1(crabs_pos: &Vec<u32>) -> u32 {
+        let min_pos = crabs_pos.iter().min().unwrap();
+        let max_pos = crabs_pos.iter().max().unwrap();
+
+        let mut min_cost = u32::MAX;
+
+        for i in *min_pos..max_pos + 1 {
+            let mut sum = 0;
+            for c in crabs_pos.iter() {
+                let cost = i.abs_diff(*c);
+                sum += cost;
+            }
+            if sum < min_cost {
+                min_cost = sum;
+            }
+        }
+
+        min_cost
+    }
+
+    fn part2(crabs_pos: &Vec<u32>) -> u32 {
+        let min_pos = crabs_pos.iter().min().unwrap();
+        let max_pos = crabs_pos.iter().max().unwrap();
+
+        let mut min_cost = u32::MAX;
+
+        for i in *min_pos..max_pos + 1 {
+            let mut sum = 0;
+            for c in crabs_pos.iter() {
+                let mut cost = i.abs_diff(*c);
+                // https://en.wikipedia.org/wiki/1_%2B_2_%2B_3_%2B_4_%2B_%E2%8B%AF
+                cost = cost * (cost + 1) / 2;
+                sum += cost;
+            }
+            if sum < min_cost {
+                min_cost = sum;
+            }
+        }
+
+        min_cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day07>();
+    }
+}
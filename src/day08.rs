@@ -1,14 +1,26 @@
-use aoc_2021::read_lines_as_vec;
+//! Day 8: Seven Segment Search
+
+use crate::parse::input_lines;
+use crate::{debug, trace, Example, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
 
+pub struct Day08;
+
+/// The ten unique signal patterns and the four digit output value of one display.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Entry {
+    pub patterns: Vec<String>,
+    pub output: Vec<String>,
+}
+
 #[derive(Debug, Eq, PartialEq)]
 struct Digit {
     segment_count: usize,
 }
 
-fn part1(lines: &[String]) -> u32 {
-    // 321
-    let digits = vec![
+/// Number of 1, 4, 7 and 8 digits in the output values.
+pub fn count_easy_digits(entries: &[Entry]) -> u32 {
+    let digits = [
         Digit { segment_count: 2 },
         Digit { segment_count: 4 },
         Digit { segment_count: 3 },
@@ -16,15 +28,8 @@ fn part1(lines: &[String]) -> u32 {
     ];
     let mut sum = 0;
 
-    for line in lines.iter() {
-        let parts = line.split("|").collect::<Vec<_>>();
-        let pattern_part = parts.first().unwrap();
-        let output_part = parts.last().unwrap();
-
-        let signal_patterns = pattern_part.split_ascii_whitespace().collect::<Vec<_>>();
-        let output_value = output_part.split_ascii_whitespace().collect::<Vec<_>>();
-
-        for s in output_value.iter() {
+    for entry in entries.iter() {
+        for s in entry.output.iter() {
             let len = s.len();
             if digits.contains(&Digit { segment_count: len }) {
                 sum += 1;
@@ -34,17 +39,17 @@ fn part1(lines: &[String]) -> u32 {
     sum
 }
 
-fn part2(lines: &[String]) -> u32 {
-    // 1028926
+/// Sum of all decoded output values.
+pub fn sum_output_values(entries: &[Entry]) -> u32 {
     let mut sum = 0;
 
-    for line in lines.iter() {
-        let parts = line.split("|").collect::<Vec<_>>();
-        let pattern_part = parts.first().unwrap();
-        let output_part = parts.last().unwrap();
-
-        let mut signal_patterns = pattern_part.split_ascii_whitespace().collect::<Vec<_>>();
-        let output_value = output_part.split_ascii_whitespace().collect::<Vec<_>>();
+    for entry in entries.iter() {
+        let mut signal_patterns = entry
+            .patterns
+            .iter()
+            .map(|p| p.as_str())
+            .collect::<Vec<_>>();
+        let output_value = entry.output.iter().map(|o| o.as_str()).collect::<Vec<_>>();
 
         //   0
         // 1   2
@@ -54,9 +59,9 @@ fn part2(lines: &[String]) -> u32 {
         let mut segement_map: HashMap<char, usize> = HashMap::new();
         let mut rev_segement_map: HashMap<usize, char> = HashMap::new();
 
-        signal_patterns.sort_by(|a, b| a.len().cmp(&b.len()));
+        signal_patterns.sort_by_key(|a| a.len());
 
-        let one_signal = signal_patterns.get(0).unwrap();
+        let one_signal = signal_patterns.first().unwrap();
         let seven_signal = signal_patterns.get(1).unwrap();
         let four_signal = signal_patterns.get(2).unwrap();
         let eight_signal = signal_patterns.get(9).unwrap();
@@ -75,7 +80,7 @@ fn part2(lines: &[String]) -> u32 {
         // segment 0 = 7 - 2
         let zero_char = seven_signal_chars
             .difference(&one_signal_chars)
-            .nth(0)
+            .next()
             .unwrap();
         segement_map.insert(*zero_char, 0);
         rev_segement_map.insert(0, *zero_char);
@@ -102,28 +107,28 @@ fn part2(lines: &[String]) -> u32 {
             });
             let eight_chars_diff_one: HashSet<char> = eight_signal_chars
                 .difference(&six_signal_chars)
-                .map(|c| *c)
+                .copied()
                 .collect();
-            if one_signal_chars.contains(&eight_chars_diff_one.iter().nth(0).unwrap()) {
+            if one_signal_chars.contains(eight_chars_diff_one.iter().next().unwrap()) {
                 // -> 8 - 6 - 1 -> segment 5
                 let c = one_signal_chars
                     .difference(&eight_chars_diff_one)
-                    .map(|c| *c)
-                    .nth(0)
+                    .copied()
+                    .next()
                     .unwrap();
                 segement_map.insert(c, 5);
                 rev_segement_map.insert(5, c);
 
-                segement_map.insert(*eight_chars_diff_one.iter().nth(0).unwrap(), 2);
-                rev_segement_map.insert(2, *eight_chars_diff_one.iter().nth(0).unwrap());
-            } else if !four_signal_chars.contains(&eight_chars_diff_one.iter().nth(0).unwrap()) {
+                segement_map.insert(*eight_chars_diff_one.iter().next().unwrap(), 2);
+                rev_segement_map.insert(2, *eight_chars_diff_one.iter().next().unwrap());
+            } else if !four_signal_chars.contains(eight_chars_diff_one.iter().next().unwrap()) {
                 // -> 9 - 4 -> segment 4 (upper segment of 4) is handled before
-                segement_map.insert(*eight_chars_diff_one.iter().nth(0).unwrap(), 4);
-                rev_segement_map.insert(4, *eight_chars_diff_one.iter().nth(0).unwrap());
+                segement_map.insert(*eight_chars_diff_one.iter().next().unwrap(), 4);
+                rev_segement_map.insert(4, *eight_chars_diff_one.iter().next().unwrap());
             } else {
                 // -> 0 -> segment 3
-                segement_map.insert(*eight_chars_diff_one.iter().nth(0).unwrap(), 3);
-                rev_segement_map.insert(3, *eight_chars_diff_one.iter().nth(0).unwrap());
+                segement_map.insert(*eight_chars_diff_one.iter().next().unwrap(), 3);
+                rev_segement_map.insert(3, *eight_chars_diff_one.iter().next().unwrap());
             }
         }
 
@@ -136,23 +141,23 @@ fn part2(lines: &[String]) -> u32 {
 
         let c = four_signal_chars
             .difference(&four_signal_chars_reminder)
-            .map(|c| *c)
-            .nth(0)
+            .copied()
+            .next()
             .unwrap();
         segement_map.insert(c, 1);
         rev_segement_map.insert(1, c);
 
         // segment 6 is the only one remaining in diff to all segments of 8
-        let segment_chars_known = segement_map.keys().map(|n| *n).collect::<HashSet<_>>();
+        let segment_chars_known = segement_map.keys().copied().collect::<HashSet<_>>();
         let c = eight_signal_chars
             .difference(&segment_chars_known)
-            .map(|c| *c)
-            .nth(0)
+            .copied()
+            .next()
             .unwrap();
         segement_map.insert(c, 6);
         rev_segement_map.insert(6, c);
 
-        // println!("{:?}", segement_map);
+        trace!("segments {:?}", segement_map);
 
         // yes we could handle unique length digits 1,4,7,8 without map
         // map sorted chars to digit
@@ -235,7 +240,7 @@ fn part2(lines: &[String]) -> u32 {
             digits_map.insert(v, i);
         }
 
-        // println!("{:?}", digits_map);
+        trace!("digits {:?}", digits_map);
         let mut digits_str = "".to_string();
         for output in output_value.iter() {
             let mut chars = output.chars().collect::<Vec<_>>();
@@ -244,79 +249,70 @@ fn part2(lines: &[String]) -> u32 {
             digits_str += d.to_string().as_str();
         }
         let digits_value: u32 = digits_str.parse().unwrap();
-        // println!("{}", digits_value);
+        debug!("output value {}", digits_value);
         sum += digits_value;
     }
     sum
 }
 
-fn main() {
-    let lines = read_lines_as_vec("input/input_day8.txt").unwrap();
-
-    //     let lines = vec![
-    //         "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |
-    // fdgacbe cefdb cefbgd gcbe",
-    //         "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec |
-    // fcgedb cgb dgebacf gc",
-    //         "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef |
-    // cg cg fdcagb cbg",
-    //         "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega |
-    // efabcd cedba gadfec cb",
-    //         "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga |
-    // gecf egdcabf bgf bfgea",
-    //         "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf |
-    // gebdcfa ecba ca fadegcb",
-    //         "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf |
-    // cefg dcbef fcge gbcadfe",
-    //         "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd |
-    // ed bcgafe cdgba cbgef",
-    //         "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg |
-    // gbdfcae bgc cg cgb",
-    //         "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc |
-    // fgae cfgab fg bagce",
-    //     ]
-    //     .iter()
-    //     .map(|s| s.to_string())
-    //     .collect::<Vec<_>>();
-    println!("{}", part1(&lines));
-    println!("{}", part2(&lines));
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+    part1: Some("26"),
+    part2: Some("61229"),
+}];
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<Entry>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: &[String]) -> Result<Vec<Entry>, ParseError> {
+        let words = |part: &str| {
+            part.split_ascii_whitespace()
+                .map(|word| word.to_string())
+                .collect()
+        };
+        input_lines(Self::DAY, lines)
+            .map(|line| {
+                let (patterns, output) = line.split_once("|")?;
+                Ok(Entry {
+                    patterns: words(patterns),
+                    output: words(output),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(entries: &Vec<Entry>) -> u32 {
+        count_easy_digits(entries)
+    }
+
+    fn part2(entries: &Vec<Entry>) -> u32 {
+        sum_output_values(entries)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{part1, part2};
+    use super::*;
+    use crate::examples::check_examples;
 
     #[test]
     fn it_works() {
-        let lines = vec![
-            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb |
-fdgacbe cefdb cefbgd gcbe",
-            "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec |
-            fcgedb cgb dgebacf gc",
-            "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef |
-            cg cg fdcagb cbg",
-            "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega |
-            efabcd cedba gadfec cb",
-            "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga |
-            gecf egdcabf bgf bfgea",
-            "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf |
-            gebdcfa ecba ca fadegcb",
-            "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf |
-            cefg dcbef fcge gbcadfe",
-            "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd |
-            ed bcgafe cdgba cbgef",
-            "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg |
-            gbdfcae bgc cg cgb",
-            "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc |
-            fgae cfgab fg bagce",
-        ]
-        .iter()
-        .map(|s| s.to_string())
-        .collect::<Vec<_>>();
-
-        let result = part1(&lines);
-        assert_eq!(result, 26);
-        let result = part2(&lines);
-        assert_eq!(result, 61229);
+        check_examples::<Day08>();
     }
 }
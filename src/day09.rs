@@ -0,0 +1,144 @@
+//! Day 9: Smoke Basin
+
+use crate::grid::Pos;
+use crate::pnm::{label_colour, Pnm, BLACK};
+use crate::{Example, Grid, Outcome, ParseError, Solution};
+use std::collections::HashSet;
+use thiserror::Error;
+
+pub struct Day09;
+
+fn get_basin_neighbours(grid: &Grid<u32>, pos: Pos) -> Vec<Pos> {
+    grid.neighbours4(pos)
+        .filter(|pos| grid[*pos] != 9)
+        .collect::<Vec<_>>()
+}
+
+fn collect_basin_neighbours(grid: &Grid<u32>, pos: Pos, basin_list: &mut HashSet<Pos>) {
+    basin_list.insert(pos);
+    let neighbours = get_basin_neighbours(grid, pos);
+    for n in neighbours {
+        if !basin_list.contains(&n) {
+            collect_basin_neighbours(grid, n, basin_list);
+        }
+    }
+}
+
+/// Positions lower than all of their neighbours.
+pub fn low_points(grid: &Grid<u32>) -> Vec<Pos> {
+    grid.iter()
+        .filter(|(pos, height)| grid.neighbours4(*pos).all(|n| grid[n] > **height))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// Sum of the risk levels of all low points.
+pub fn risk_level_sum(grid: &Grid<u32>) -> u32 {
+    low_points(grid).iter().map(|pos| 1 + grid[*pos]).sum()
+}
+
+/// The basin around every low point, in the order of [`low_points`].
+pub fn basins(grid: &Grid<u32>) -> Vec<HashSet<Pos>> {
+    low_points(grid)
+        .iter()
+        .map(|l| {
+            let mut basin_list = HashSet::new();
+            collect_basin_neighbours(grid, *l, &mut basin_list);
+            basin_list
+        })
+        .collect()
+}
+
+/// The map has fewer than the three basins part 2 multiplies.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
+#[error("expected at least 3 basins, got {found}")]
+pub struct TooFewBasins {
+    pub found: usize,
+}
+
+/// Product of the sizes of the three largest basins.
+pub fn basin_product(grid: &Grid<u32>) -> Result<u32, TooFewBasins> {
+    let mut nigh_score_basins = basins(grid)
+        .iter()
+        .map(|basin| basin.len() as u32)
+        .collect::<Vec<_>>();
+    nigh_score_basins.sort();
+    let found = nigh_score_basins.len();
+    if found < 3 {
+        return Err(TooFewBasins { found });
+    }
+    Ok(nigh_score_basins[found - 3..].iter().product::<u32>())
+}
+
+// every basin in its own colour, the 9 ridges between them black
+fn dump(grid: &Grid<u32>) -> Pnm {
+    let mut image = Grid::new(grid.cols(), grid.lines(), BLACK);
+    for (label, basin) in basins(grid).iter().enumerate() {
+        for pos in basin.iter() {
+            image[*pos] = label_colour(label);
+        }
+    }
+    Pnm::Pixmap(image)
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678",
+    part1: Some("15"),
+    part2: Some("1134"),
+}];
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const DUMP: Option<fn(&Grid<u32>) -> Pnm> = Some(dump);
+
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = Outcome<u32, TooFewBasins>;
+
+    fn parse(lines: &[String]) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(Self::DAY, lines, |c| c.to_digit(10), "not a digit")
+    }
+
+    fn part1(grid: &Grid<u32>) -> u32 {
+        risk_level_sum(grid)
+    }
+
+    fn part2(grid: &Grid<u32>) -> Outcome<u32, TooFewBasins> {
+        basin_product(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day09>();
+
+        // two basins split by a ridge
+        let grid = Day09::parse(&["191".to_string()]).unwrap();
+        assert_eq!(basin_product(&grid), Err(TooFewBasins { found: 2 }));
+
+        let example = Day09::parse(&EXAMPLES[0].lines()).unwrap();
+        let image = dump(&example);
+        assert!(image.to_string().starts_with("P3\n10 5\n255\n"));
+        match image {
+            Pnm::Pixmap(image) => {
+                assert_eq!(image[(2, 0)], BLACK);
+                // the top left and top right basins
+                assert_eq!(image[(0, 0)], image[(1, 0)]);
+                assert_ne!(image[(0, 0)], image[(9, 0)]);
+            }
+            other => panic!("expected a pixmap, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Day 10: Syntax Scoring
+
+use crate::parse::input_lines;
+use crate::{Example, ParseError, Solution};
+use std::collections::VecDeque;
+
+pub struct Day10;
+
+/// Total score of the first illegal character of every corrupted line.
+pub fn syntax_error_score(lines: &[String]) -> u32 {
+    let mut sum = 0;
+    for line in lines.iter() {
+        let mut stack = VecDeque::new();
+        let mut error_type: char = 'c';
+        for c in line.chars() {
+            match c {
+                '(' => stack.push_front('('),
+                ')' if stack.pop_front().unwrap() != '(' => {
+                    error_type = ')';
+                    break;
+                }
+                '[' => stack.push_front('['),
+                ']' if stack.pop_front().unwrap() != '[' => {
+                    error_type = ']';
+                    break;
+                }
+                '{' => stack.push_front('{'),
+                '}' if stack.pop_front().unwrap() != '{' => {
+                    error_type = '}';
+                    break;
+                }
+                '<' => stack.push_front('<'),
+
+                '>' if stack.pop_front().unwrap() != '<' => {
+                    error_type = '>';
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        match error_type {
+            ')' => sum += 3,
+            ']' => sum += 57,
+            '}' => sum += 1197,
+            '>' => sum += 25137,
+            _ => {}
+        }
+    }
+
+    sum
+}
+
+/// Middle score of the completions of all incomplete lines.
+pub fn completion_score(lines: &[String]) -> usize {
+    let mut score_list: Vec<usize> = vec![];
+    for line in lines.iter() {
+        let mut stack = VecDeque::new();
+        let mut error_type: char = '-';
+        for c in line.chars() {
+            match c {
+                '(' => stack.push_front('('),
+                ')' if stack.pop_front().unwrap() != '(' => {
+                    error_type = ')';
+                    break;
+                }
+                '[' => stack.push_front('['),
+                ']' if stack.pop_front().unwrap() != '[' => {
+                    error_type = ']';
+                    break;
+                }
+                '{' => stack.push_front('{'),
+                '}' if stack.pop_front().unwrap() != '{' => {
+                    error_type = '}';
+                    break;
+                }
+                '<' => stack.push_front('<'),
+
+                '>' if stack.pop_front().unwrap() != '<' => {
+                    error_type = '>';
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        if error_type == '-' {
+            let mut complete_line = "".to_string();
+            while let Some(c) = stack.pop_front() {
+                match c {
+                    '(' => complete_line += ')'.to_string().as_str(),
+                    '[' => complete_line += ']'.to_string().as_str(),
+                    '{' => complete_line += '}'.to_string().as_str(),
+                    '<' => complete_line += '>'.to_string().as_str(),
+                    _ => {}
+                }
+            }
+            let mut score = 0;
+            for c in complete_line.chars() {
+                match c {
+                    ')' => score = score * 5 + 1,
+                    ']' => score = score * 5 + 2,
+                    '}' => score = score * 5 + 3,
+                    '>' => score = score * 5 + 4,
+                    _ => {}
+                }
+            }
+            score_list.push(score);
+        }
+    }
+
+    score_list.sort();
+    score_list[score_list.len() / 2]
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]",
+    part1: Some("26397"),
+    part2: Some("288957"),
+}];
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(lines: &[String]) -> Result<Vec<String>, ParseError> {
+        for line in input_lines(Self::DAY, lines) {
+            if let Some(i) = line.text.find(|c: char| !"()[]{}<>".contains(c)) {
+                return Err(line.error(&line.text[i..i + 1], "not a bracket"));
+            }
+        }
+        Ok(lines.to_vec())
+    }
+
+    fn part1(lines: &Vec<String>) -> u32 {
+        syntax_error_score(lines)
+    }
+
+    fn part2(lines: &Vec<String>) -> usize {
+        completion_score(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day10>();
+    }
+}
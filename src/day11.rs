@@ -0,0 +1,174 @@
+//! Day 11: Dumbo Octopus
+
+use crate::grid::Pos;
+use crate::simulate::{simulate, Cycled, Outcome as Ending, Stop};
+use crate::visualize::{is_recording, record};
+use crate::{Example, Grid, Outcome, ParseError, Solution};
+
+pub struct Day11;
+
+fn flash_o_bang(
+    grid: &mut Grid<u32>,
+    flash_list: &[Pos],
+    all_flash_list: &mut Vec<Pos>,
+    flashes: &mut usize,
+) {
+    let mut new_flash_list = vec![];
+    for pos in flash_list.iter() {
+        let neighbours = grid.neighbours8(*pos).collect::<Vec<_>>();
+        for n in neighbours.iter() {
+            let energy_level = grid[*n];
+            if energy_level == 9 {
+                // flashes this round - all flashes will be set to 0 later
+                new_flash_list.push(*n);
+                all_flash_list.push(*n);
+            }
+            grid[*n] = energy_level + 1;
+        }
+    }
+    *flashes += new_flash_list.len();
+    if !new_flash_list.is_empty() {
+        flash_o_bang(grid, &new_flash_list, all_flash_list, flashes);
+    }
+}
+
+/// Runs one step in place, returns the positions that flashed and adds them to `flashes`.
+pub fn step(grid: &mut Grid<u32>, flashes: &mut usize) -> Vec<Pos> {
+    let mut flash_list = vec![];
+    let mut all_flash_list = vec![];
+
+    // 1: -> increase all by 1
+    for pos in grid.positions() {
+        let energy_level = grid[pos];
+
+        if energy_level == 9 {
+            // flashes this round - all flashes will be set to 0 later
+            flash_list.push(pos);
+            all_flash_list.push(pos);
+        }
+        grid[pos] = energy_level + 1;
+    }
+    *flashes += flash_list.len();
+
+    // 2: -> recursivly increase all neighbours
+    flash_o_bang(grid, &flash_list, &mut all_flash_list, flashes);
+
+    // 3: -> now set all that flashed this round to 0
+    all_flash_list.iter().for_each(|pos| {
+        grid[*pos] = 0;
+    });
+    all_flash_list
+}
+
+/// Number of flashes in the first 100 steps.
+pub fn count_flashes(grid: &Grid<u32>) -> usize {
+    let mut grid = grid.clone();
+    record("step 0", || grid.to_string());
+
+    let steps = 100;
+    let mut flashes = 0;
+
+    for n in 1..=steps {
+        step(&mut grid, &mut flashes);
+        record(format!("step {} flashes {}", n, flashes), || {
+            grid.to_string()
+        });
+    }
+
+    flashes
+}
+
+/// First step in which every octopus flashes, or the cycle the octopuses go round in when
+/// they never flash at once.
+pub fn first_sync_step(grid: &Grid<u32>) -> Result<u32, Cycled> {
+    record("step 0", || grid.to_string());
+
+    let next = |grid: &Grid<u32>| {
+        let mut grid = grid.clone();
+        step(&mut grid, &mut 0);
+        grid
+    };
+    // all flashed when all are 0 afterwards
+    let sync = simulate(
+        grid.clone(),
+        next,
+        Stop::When(&|grid| grid.values().all(|energy_level| *energy_level == 0)),
+    );
+    match sync.outcome {
+        Ending::Stopped => {}
+        // a grid a step doesn't change goes round in a cycle of one step
+        Ending::Fixpoint => {
+            return Err(Cycled {
+                start: sync.steps,
+                period: 1,
+            })
+        }
+        Ending::Cycle { start, period } => return Err(Cycled { start, period }),
+    }
+
+    // simulate may run a step twice, the frames come from a run of their own
+    if is_recording() {
+        let mut grid = grid.clone();
+        for n in 1..=sync.steps {
+            grid = next(&grid);
+            record(format!("step {}", n), || grid.to_string());
+        }
+    }
+    Ok(sync.steps as u32)
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526",
+    part1: Some("1656"),
+    part2: Some("195"),
+}];
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = Outcome<u32, Cycled>;
+
+    fn parse(lines: &[String]) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(Self::DAY, lines, |c| c.to_digit(10), "not a digit")
+    }
+
+    fn part1(grid: &Grid<u32>) -> usize {
+        count_flashes(grid)
+    }
+
+    fn part2(grid: &Grid<u32>) -> Outcome<u32, Cycled> {
+        first_sync_step(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day11>();
+
+        // the two octopuses take turns flashing and are back where they started after 9 steps
+        let pair = Day11::parse(&["02".to_string()]).unwrap();
+        assert_eq!(
+            Day11::part2(&pair).to_string(),
+            "no answer: the state at step 0 comes back every 9 steps"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Day 12: Passage Pathing
+
+use crate::generate::{Generator, Rng};
+use crate::parse::{input_line, input_lines};
+use crate::{trace, Example, ParseError, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day12;
+
+// every small only once
+fn test_small_caves_once(path: &Vec<&str>) -> bool {
+    let mut small_caves = HashSet::new();
+    for p in path.iter() {
+        let is_lowercase = p.chars().all(|c| c.is_lowercase());
+        if is_lowercase {
+            if small_caves.contains(p) {
+                return false;
+            }
+            small_caves.insert(p);
+        }
+    }
+    true
+}
+
+fn has_any_small_cave_twice<'a>(small_caves: &'a Vec<&'a str>) -> Option<&'a str> {
+    for small_cave in small_caves.iter() {
+        let count = small_caves.iter().filter(|c| ***c == **small_cave).count();
+        if count == 2 {
+            return Some(small_cave);
+        }
+    }
+    None
+}
+// one small twice - rest only once
+fn test_small_caves_twice(path: &Vec<&str>) -> bool {
+    let mut small_caves: Vec<&str> = vec![];
+    for p in path.iter() {
+        let is_lowercase = p.chars().all(|c| c.is_lowercase());
+        if is_lowercase {
+            if let Some(twice_small_cave) = has_any_small_cave_twice(&small_caves) {
+                // would be the third time
+                if twice_small_cave == *p {
+                    return false;
+                }
+                // another cave is already in twice so we only allow this once
+                let count = small_caves.iter().filter(|c| ***c == **p).count();
+                if count == 0 {
+                    small_caves.push(p);
+                } else {
+                    return false;
+                }
+            } else {
+                small_caves.push(p);
+            }
+        }
+    }
+    true
+}
+
+// the caves every cave has a passage to
+type Graph<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn bfs(
+    graph: &Graph,
+    start: &str,
+    end: &str,
+    small_cave_test: fn(path: &Vec<&str>) -> bool,
+) -> usize {
+    let mut stack = VecDeque::new();
+    stack.push_back(vec![start]);
+
+    let mut seen: HashMap<&str, u32> = HashMap::new();
+    seen.insert(start, 0);
+
+    let mut path_count = 0;
+    while let Some(path) = stack.pop_front() {
+        let tail = *path.last().unwrap();
+        if tail == end {
+            trace!("path {:?}", path);
+            path_count += 1;
+            continue;
+        }
+
+        // parse made sure there is a start, every other cave is in a passage
+        for move_pos in graph[tail].iter() {
+            if *move_pos != start {
+                let mut p = path.clone();
+                p.push(move_pos);
+
+                if small_cave_test(&path) {
+                    stack.push_back(p);
+                }
+            }
+        }
+    }
+    path_count
+}
+
+fn build_graph(edges: &[(String, String)]) -> Graph<'_> {
+    let mut graph = Graph::new();
+    for (n1, n2) in edges.iter() {
+        graph.entry(n1).or_default().push(n2);
+        graph.entry(n2).or_default().push(n1);
+    }
+    graph
+}
+
+fn cave_name(index: usize, first: u8) -> String {
+    let letter = |i: usize| (first + i as u8) as char;
+    format!("{}{}", letter(index / 26 % 26), letter(index % 26))
+}
+
+// size small caves and a third as many big ones, big caves never touch each other
+// or the number of paths would be infinite
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let small = (0..size.max(1))
+        .map(|i| cave_name(i, b'a'))
+        .collect::<Vec<_>>();
+    let big = (0..size / 3 + 1)
+        .map(|i| cave_name(i, b'A'))
+        .collect::<Vec<_>>();
+
+    let mut edges: Vec<(String, String)> = vec![];
+    let mut add_edge = |a: &String, b: &String| {
+        let edge = (a.clone(), b.clone());
+        let reversed = (edge.1.clone(), edge.0.clone());
+        if !edges.contains(&edge) && !edges.contains(&reversed) {
+            edges.push(edge);
+        }
+    };
+
+    let (start, end) = ("start".to_string(), "end".to_string());
+
+    // every small cave hangs off start, an earlier small cave or a big cave so all are reachable
+    for (i, cave) in small.iter().enumerate() {
+        match rng.below(3) {
+            0 if i > 0 => add_edge(&small[rng.below(i)], cave),
+            1 => add_edge(rng.pick(&big), cave),
+            _ => add_edge(&start, cave),
+        }
+    }
+    for cave in big.iter() {
+        add_edge(cave, rng.pick(&small));
+        add_edge(cave, rng.pick(&small));
+    }
+    add_edge(rng.pick(&small), &end);
+    add_edge(rng.pick(&big), &end);
+
+    edges.iter().map(|(a, b)| format!("{}-{}", a, b)).collect()
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end",
+    part1: Some("10"),
+    part2: Some("36"),
+}];
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input = Vec<(String, String)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: &[String]) -> Result<Vec<(String, String)>, ParseError> {
+        let edges = input_lines(Self::DAY, lines)
+            .map(|line| {
+                let (n1, n2) = line.split_once("-")?;
+                Ok((n1.to_string(), n2.to_string()))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        // the paths run from start to end, both caves have to be in a passage
+        for cave in ["start", "end"] {
+            if !edges.iter().any(|(n1, n2)| n1 == cave || n2 == cave) {
+                let last = input_line(Self::DAY, lines, lines.len().saturating_sub(1))?;
+                return Err(last.error(last.end(), format!("missing a passage to {} at", cave)));
+            }
+        }
+        Ok(edges)
+    }
+
+    fn part1(edges: &Vec<(String, String)>) -> usize {
+        let graph = build_graph(edges);
+        bfs(&graph, "start", "end", test_small_caves_once)
+    }
+
+    fn part2(edges: &Vec<(String, String)>) -> usize {
+        let graph = build_graph(edges);
+        bfs(&graph, "start", "end", test_small_caves_twice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day12>();
+
+        let lines = ["start-A", "A-b"].map(|s| s.to_string());
+        assert_eq!(
+            Day12::parse(&lines).err().unwrap().to_string(),
+            "day 12 line 2 column 4: missing a passage to end at `'"
+        );
+        let lines = ["b-end"].map(|s| s.to_string());
+        assert_eq!(
+            Day12::parse(&lines).err().unwrap().to_string(),
+            "day 12 line 1 column 6: missing a passage to start at `'"
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Day 13: Transparent Origami
+
+use crate::ocr::{read_letters, OcrError};
+use crate::parse::{sections, Line};
+use crate::pnm::Pnm;
+use crate::{Example, Grid, Outcome, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day13;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Fold {
+    Horizontal(i16),
+    Vertical(i16),
+}
+
+// the paper starts at 0,0 so neither dots nor folds can be negative
+fn read_coordinate(line: &Line, token: &str) -> Result<i16, ParseError> {
+    match line.parse::<i16>(token)? {
+        value if value < 0 => Err(line.error(token, "expected 0 or more, got")),
+        value => Ok(value),
+    }
+}
+
+// x,y
+struct Dot(i16, i16);
+
+impl Dot {
+    fn parse(line: &Line) -> Result<Dot, ParseError> {
+        let (x, y) = line.split_once(",")?;
+        Ok(Dot(read_coordinate(line, x)?, read_coordinate(line, y)?))
+    }
+}
+
+impl Fold {
+    // fold along x=5
+    fn parse(line: &Line) -> Result<Fold, ParseError> {
+        let (axis, value) = line
+            .text
+            .strip_prefix("fold along ")
+            .and_then(|fold| fold.split_once('='))
+            .ok_or_else(|| line.error(line.text, "expected fold along x=<n> or y=<n>, got"))?;
+        let value = read_coordinate(line, value)?;
+        match axis {
+            "y" => Ok(Fold::Horizontal(value)),
+            "x" => Ok(Fold::Vertical(value)),
+            _ => Err(line.error(axis, "expected fold along x or y, got")),
+        }
+    }
+}
+
+// the paper is at least as large as its dots, a fold along a line past that is fine as long
+// as the paper wasn't folded that way yet. the fold must not put any dot past the edge, so it
+// can't be in the upper or left half of the paper
+fn check_fold(
+    line: &Line,
+    size: i16,
+    folded: bool,
+    value: i16,
+    side: &str,
+) -> Result<(), ParseError> {
+    let token = line.text.rsplit('=').next().unwrap_or(line.text);
+    if folded && value >= size {
+        Err(line.error(
+            token,
+            format!("expected a fold inside the {} {} paper, got", size, side),
+        ))
+    } else if 2 * i32::from(value) + 1 < i32::from(size) {
+        Err(line.error(
+            token,
+            format!(
+                "expected a fold in the second half of the {} {} paper, got",
+                size, side
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+pub struct Paper {
+    grid: HashMap<(i16, i16), bool>,
+    width: i16,
+    height: i16,
+    folds: Vec<Fold>,
+}
+
+fn fold_horizontal(grid: &HashMap<(i16, i16), bool>, y_fold: i16) -> HashMap<(i16, i16), bool> {
+    let mut folded_grid: HashMap<(i16, i16), bool> = HashMap::new();
+    for (x, y) in grid.keys() {
+        if *y > y_fold {
+            folded_grid.insert((*x, y_fold - (y - y_fold)), true);
+        } else {
+            folded_grid.insert((*x, *y), true);
+        }
+    }
+    folded_grid
+}
+fn fold_vertical(grid: &HashMap<(i16, i16), bool>, x_fold: i16) -> HashMap<(i16, i16), bool> {
+    let mut folded_grid: HashMap<(i16, i16), bool> = HashMap::new();
+    for (x, y) in grid.keys() {
+        if *x > x_fold {
+            folded_grid.insert((x_fold - (x - x_fold), *y), true);
+        } else {
+            folded_grid.insert((*x, *y), true);
+        }
+    }
+    folded_grid
+}
+
+fn fold(paper: &Paper, fold_once: bool) -> (HashMap<(i16, i16), bool>, i16, i16) {
+    let mut current_width = paper.width;
+    let mut current_height = paper.height;
+    let mut folded_grid = paper.grid.clone();
+    for fold in paper.folds.iter() {
+        match fold {
+            // the fold line itself is gone, the paper ends right before it
+            Fold::Horizontal(y) => {
+                folded_grid = fold_horizontal(&folded_grid, *y);
+                current_height = *y;
+            }
+            Fold::Vertical(x) => {
+                folded_grid = fold_vertical(&folded_grid, *x);
+                current_width = *x;
+            }
+        }
+        if fold_once {
+            break;
+        }
+    }
+    (folded_grid, current_width, current_height)
+}
+
+/// The paper after all folds, true where there is a dot.
+pub fn folded_paper(paper: &Paper) -> Grid<bool> {
+    let (folded_grid, width, height) = fold(paper, false);
+    Grid::from_fn(width as usize, height as usize, |(x, y)| {
+        folded_grid.contains_key(&(x as i16, y as i16))
+    })
+}
+
+// the folded paper, dots are black
+fn dump(paper: &Paper) -> Pnm {
+    Pnm::Bitmap(folded_paper(paper))
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "letters",
+        input: "\
+1,12
+2,12
+0,1
+3,1
+0,2
+3,2
+0,9
+1,9
+2,9
+3,9
+0,8
+3,8
+0,7
+3,7
+6,12
+7,12
+5,1
+8,1
+5,2
+8,2
+5,9
+8,9
+5,8
+8,8
+6,7
+7,7
+11,12
+12,12
+10,1
+13,1
+10,2
+10,9
+10,8
+13,8
+11,7
+12,7
+
+fold along y=6",
+        part1: Some("36"),
+        part2: Some("AOC"),
+    },
+    Example {
+        name: "example",
+        input: "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5",
+        part1: Some("17"),
+        // folds into a square that isn't a letter
+        part2: None,
+    },
+];
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const DUMP: Option<fn(&Paper) -> Pnm> = Some(dump);
+
+    type Input = Paper;
+    type Answer1 = usize;
+    type Answer2 = Outcome<String, OcrError>;
+
+    fn parse(lines: &[String]) -> Result<Paper, ParseError> {
+        let sections = sections(Self::DAY, lines);
+        sections.expect(2)?;
+        let dots = sections
+            .get(0)?
+            .lines
+            .iter()
+            .map(Dot::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let width = dots.iter().map(|dot| dot.0).max().unwrap() + 1;
+        let height = dots.iter().map(|dot| dot.1).max().unwrap() + 1;
+        let grid = dots.iter().map(|dot| ((dot.0, dot.1), true)).collect();
+
+        let (mut current_width, mut current_height) = (width, height);
+        let (mut folded_x, mut folded_y) = (false, false);
+        let mut folds = vec![];
+        for line in sections.get(1)?.lines.iter() {
+            let fold = Fold::parse(line)?;
+            match fold {
+                Fold::Horizontal(y) => {
+                    check_fold(line, current_height, folded_y, y, "high")?;
+                    (current_height, folded_y) = (y, true);
+                }
+                Fold::Vertical(x) => {
+                    check_fold(line, current_width, folded_x, x, "wide")?;
+                    (current_width, folded_x) = (x, true);
+                }
+            }
+            folds.push(fold);
+        }
+
+        Ok(Paper {
+            grid,
+            width,
+            height,
+            folds,
+        })
+    }
+
+    fn part1(paper: &Paper) -> usize {
+        fold(paper, true).0.len()
+    }
+
+    fn part2(paper: &Paper) -> Outcome<String, OcrError> {
+        read_letters(&folded_paper(paper)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day13>();
+
+        let example = Day13::parse(&EXAMPLES[1].lines()).unwrap();
+        assert_eq!(
+            Day13::part2(&example).to_string(),
+            "no answer: expected letters 6 dots high, got 5:\n#####\n#...#\n#...#\n#...#\n#####\n.....\n....."
+        );
+
+        // no dot on the far edges, the size still comes from the folds
+        let lines = ["0,0", "", "fold along y=5", "fold along x=3"].map(|s| s.to_string());
+        let paper = folded_paper(&Day13::parse(&lines).unwrap());
+        assert_eq!((paper.cols(), paper.lines()), (3, 5));
+
+        // AOC, 14 dots wide and 6 high once folded
+        let letters = Day13::parse(&EXAMPLES[0].lines()).unwrap();
+        let image = dump(&letters);
+        assert!(image
+            .to_string()
+            .starts_with("P1\n14 6\n0 1 1 0 0 0 1 1 0 0 0 1 1 0\n"));
+        match image {
+            Pnm::Bitmap(paper) => assert!(paper[(0, 1)] && paper[(13, 1)] && !paper[(13, 2)]),
+            other => panic!("expected a bitmap, got {:?}", other),
+        }
+
+        let error = |lines: &[&str]| {
+            let lines = lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+            Day13::parse(&lines).err().unwrap().to_string()
+        };
+        assert_eq!(
+            error(&["0,-1", "", "fold along y=1"]),
+            "day 13 section 1 line 1 column 3: expected 0 or more, got `-1'"
+        );
+        assert_eq!(
+            error(&["0,1", "", "fold along x=-1"]),
+            "day 13 section 2 line 3 column 14: expected 0 or more, got `-1'"
+        );
+        assert_eq!(
+            error(&["0,14", "", "fold along y=7", "fold along y=9"]),
+            "day 13 section 2 line 4 column 14: expected a fold inside the 7 high paper, got `9'"
+        );
+        assert_eq!(
+            error(&["10,0", "", "fold along x=4"]),
+            "day 13 section 2 line 3 column 14: expected a fold in the second half of the 11 wide paper, got `4'"
+        );
+    }
+}
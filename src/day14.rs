@@ -0,0 +1,184 @@
+//! Day 14: Extended Polymerization
+
+use crate::checked::{add, Count, Overflow};
+use crate::generate::{Generator, Rng};
+use crate::parse::sections;
+use crate::simulate::{simulate, Stop};
+use crate::{Example, Outcome, ParseError, Solution};
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+pub struct Day14;
+
+// CH -> B
+struct Rule {
+    pair: (char, char),
+    insert: char,
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pair, insert) = s.split_once(" -> ").ok_or("missing ` -> ' in")?;
+        let pair = match pair.chars().collect::<Vec<_>>()[..] {
+            [a, b] => (a, b),
+            _ => return Err("expected a pair of two elements in".to_string()),
+        };
+        match insert.chars().collect::<Vec<_>>()[..] {
+            [insert] => Ok(Rule { pair, insert }),
+            _ => Err("expected a single element to insert in".to_string()),
+        }
+    }
+}
+
+pub struct Polymer {
+    template: Vec<char>,
+    rules: HashMap<(char, char), char>,
+}
+
+// adds num to the count of key
+fn insert_into<K: Ord>(map: &mut BTreeMap<K, Count>, key: K, num: Count) -> Result<(), Overflow> {
+    let count = map.entry(key).or_insert(0);
+    *count = add(*count, num)?;
+    Ok(())
+}
+
+// counts of the pairs and of the elements of a polymer
+type Counts = (BTreeMap<(char, char), Count>, BTreeMap<char, Count>);
+
+/// Most minus least common element after `steps` insertion steps.
+pub fn grow(polymer: &Polymer, steps: usize) -> Result<Count, Overflow> {
+    let init_polymer = &polymer.template;
+    let rules = &polymer.rules;
+
+    // ordered maps so the state can be hashed
+    let mut char_map: BTreeMap<char, Count> = BTreeMap::new();
+    let mut polymer_map: BTreeMap<(char, char), Count> = BTreeMap::new();
+    for i in 0..init_polymer.len() - 1 {
+        let pair = (init_polymer[i], init_polymer[i + 1]);
+        polymer_map
+            .entry((init_polymer[i], init_polymer[i + 1]))
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+        char_map.entry(pair.0).and_modify(|n| *n += 1).or_insert(1);
+    }
+    char_map
+        .entry(init_polymer[init_polymer.len() - 1])
+        .and_modify(|n| *n += 1)
+        .or_insert(1);
+
+    // an overflow ends the growth, the error maps to itself and is a fixpoint
+    let step = |counts: &Result<Counts, Overflow>| {
+        let (polymer_map, char_map) = counts.as_ref().map_err(Overflow::clone)?;
+        let mut char_map = char_map.clone();
+        let mut new_polymer_map: BTreeMap<(char, char), Count> = BTreeMap::new();
+
+        for (rules_pair, insert) in rules.iter() {
+            let new_pair_0 = (rules_pair.0, *insert);
+            let new_pair_1 = (*insert, rules_pair.1);
+
+            if polymer_map.contains_key(rules_pair) {
+                let num = *polymer_map.get(rules_pair).unwrap();
+                insert_into(&mut char_map, *insert, num)?;
+                insert_into(&mut new_polymer_map, new_pair_0, num)?;
+                insert_into(&mut new_polymer_map, new_pair_1, num)?;
+            }
+        }
+        Ok((new_polymer_map, char_map))
+    };
+    let (_, char_map) = simulate(Ok((polymer_map, char_map)), step, Stop::After(steps)).state?;
+
+    let mut v = char_map.values().copied().collect::<Vec<_>>();
+    v.sort();
+
+    Ok(v.last().unwrap() - v.first().unwrap())
+}
+
+// a template of size elements and a rule for every pair of the ten elements
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let elements = "BCFHKNOPSV".chars().collect::<Vec<_>>();
+    let template = (0..size.max(2))
+        .map(|_| *rng.pick(&elements))
+        .collect::<String>();
+
+    let mut lines = vec![template, "".to_string()];
+    for a in elements.iter() {
+        for b in elements.iter() {
+            lines.push(format!("{}{} -> {}", a, b, rng.pick(&elements)));
+        }
+    }
+    lines
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C",
+    part1: Some("1588"),
+    part2: Some("2188189693529"),
+}];
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input = Polymer;
+    type Answer1 = Outcome<Count, Overflow>;
+    type Answer2 = Outcome<Count, Overflow>;
+
+    fn parse(lines: &[String]) -> Result<Polymer, ParseError> {
+        let sections = sections(Self::DAY, lines);
+        sections.expect(2)?;
+        let template = sections.get(0)?.single()?.text.chars().collect::<Vec<_>>();
+        let rules = sections
+            .get(1)?
+            .records::<Rule>()?
+            .into_iter()
+            .map(|rule| (rule.pair, rule.insert))
+            .collect();
+        Ok(Polymer { template, rules })
+    }
+
+    fn part1(polymer: &Polymer) -> Outcome<Count, Overflow> {
+        grow(polymer, 10).into()
+    }
+
+    fn part2(polymer: &Polymer) -> Outcome<Count, Overflow> {
+        grow(polymer, 40).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day14>();
+
+        // the counts double every step, u128 runs out after about 120 of them
+        let polymer = Day14::parse(&EXAMPLES[0].lines()).unwrap();
+        let overflow = Outcome::from(grow(&polymer, 150)).to_string();
+        assert!(overflow.starts_with("no answer: `"), "{}", overflow);
+    }
+}
@@ -0,0 +1,127 @@
+//! Day 15: Chiton
+
+use crate::generate::{Generator, Rng};
+use crate::grid::Pos;
+use crate::shortest_path::astar;
+use crate::{trace, Example, Grid, ParseError, Solution};
+
+pub struct Day15;
+
+/// Lowest total risk from the top left to the bottom right corner.
+pub fn lowest_risk(grid: &Grid<u16>) -> u32 {
+    let start: Pos = (0, 0);
+    let end: Pos = (grid.cols() as i32 - 1, grid.lines() as i32 - 1);
+
+    // parse only allows risks of 1 and more so the manhattan distance never overestimates
+    astar(
+        start,
+        |pos| {
+            grid.neighbours4(*pos)
+                .map(|next_pos| (next_pos, grid[next_pos] as u32))
+        },
+        |pos| (end.0 - pos.0 + end.1 - pos.1) as u32,
+        |pos| *pos == end,
+    )
+    .map_or(u32::MAX, |path| path.cost)
+}
+
+/// The map tiled five times in both directions with increasing risk.
+pub fn full_map(grid: &Grid<u16>) -> Grid<u16> {
+    let grid_cols = grid.cols() as i32;
+    let grid_lines = grid.lines() as i32;
+
+    Grid::from_fn(grid.cols() * 5, grid.lines() * 5, |(x, y)| {
+        let value = grid[(x % grid_cols, y % grid_lines)];
+        let mul_x = x / grid_cols;
+        let mul_y = y / grid_lines;
+
+        let mut mapped_value = value + mul_x as u16 + mul_y as u16;
+        if mapped_value > 9 {
+            mapped_value -= 9;
+        }
+        mapped_value
+    })
+}
+
+fn lowest_risk_full_map(grid: &Grid<u16>) -> u32 {
+    let full_map = full_map(grid);
+    trace!("full map\n{}", full_map);
+    lowest_risk(&full_map)
+}
+
+// a size x size map of risks 1 to 9
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| char::from_digit(rng.range(1..=9) as u32, 10).unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581",
+    part1: Some("40"),
+    part2: Some("315"),
+}];
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input = Grid<u16>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: &[String]) -> Result<Grid<u16>, ParseError> {
+        Grid::parse(
+            Self::DAY,
+            lines,
+            |c| {
+                c.to_digit(10)
+                    .filter(|risk| *risk > 0)
+                    .map(|risk| risk as u16)
+            },
+            "not a risk from 1 to 9",
+        )
+    }
+
+    fn part1(grid: &Grid<u16>) -> u32 {
+        lowest_risk(grid)
+    }
+
+    fn part2(grid: &Grid<u16>) -> u32 {
+        lowest_risk_full_map(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day15>();
+
+        let lines = ["19", "01"].map(|s| s.to_string());
+        let err = Day15::parse(&lines).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 15 line 2 column 1: not a risk from 1 to 9 `0'"
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Day 16: Packet Decoder
+
+use crate::checked::{add, from_bits, mul, Count, Overflow};
+use crate::parse::{input_line, Line};
+use crate::repl::{parse_line, Repl, ReplFactory};
+use crate::{Example, Outcome, ParseError, Solution};
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
+
+pub struct Day16;
+
+fn get_hex_value(c: char) -> u32 {
+    c.to_digit(16).unwrap()
+}
+
+fn calc_operator(operator_type: usize, lhs: Count, rhs: Count) -> Result<Count, Overflow> {
+    Ok(match operator_type {
+        0 => add(lhs, rhs)?,
+        1 => mul(lhs, rhs)?,
+        2 => min(lhs, rhs),
+        3 => max(lhs, rhs),
+        5 => {
+            if lhs > rhs {
+                1
+            } else {
+                0
+            }
+        }
+        6 => {
+            if lhs < rhs {
+                1
+            } else {
+                0
+            }
+        }
+        7 => {
+            if lhs == rhs {
+                1
+            } else {
+                0
+            }
+        }
+        _ => lhs,
+    })
+}
+
+/// The transmission decoded into a tree of packets, both parts walk it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Packet {
+    // a literal too long for Count only fails the value, not the version sum
+    Literal {
+        version: usize,
+        value: Result<Count, Overflow>,
+    },
+    Operator {
+        version: usize,
+        type_id: usize,
+        packets: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    fn write_indented(&self, f: &mut Formatter<'_>, depth: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(depth);
+        match self {
+            Packet::Literal { version, value } => match value {
+                Ok(value) => writeln!(f, "{}{} v{}", indent, value, version),
+                Err(e) => writeln!(f, "{}{} v{}", indent, e, version),
+            },
+            Packet::Operator {
+                version,
+                type_id,
+                packets,
+            } => {
+                let name = match type_id {
+                    0 => "sum",
+                    1 => "product",
+                    2 => "minimum",
+                    3 => "maximum",
+                    5 => "greater than",
+                    6 => "less than",
+                    _ => "equal to",
+                };
+                writeln!(f, "{}{} v{}", indent, name, version)?;
+                for packet in packets {
+                    packet.write_indented(f, depth + 1)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// one packet per line, sub-packets indented below their operator
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_indented(f, 0)
+    }
+}
+
+/// Sum of the versions of all packets in the transmission.
+pub fn version_sum(packet: &Packet) -> usize {
+    match packet {
+        Packet::Literal { version, .. } => *version,
+        Packet::Operator {
+            version, packets, ..
+        } => version + packets.iter().map(version_sum).sum::<usize>(),
+    }
+}
+
+/// Value of the expression the outermost packet encodes.
+pub fn evaluate(packet: &Packet) -> Result<Count, Overflow> {
+    match packet {
+        Packet::Literal { value, .. } => value.clone(),
+        // decode never makes an operator without sub-packets
+        Packet::Operator {
+            type_id, packets, ..
+        } => packets
+            .iter()
+            .map(evaluate)
+            .reduce(|lhs, rhs| calc_operator(*type_id, lhs?, rhs?))
+            .unwrap_or(Ok(0)),
+    }
+}
+
+/// The packet starting at `start_idx` and the index after it, errors for cut off packets.
+// the error is the bit the problem starts at and what is wrong there
+fn decode(bits: &str, start_idx: usize) -> Result<(Packet, usize), (usize, &'static str)> {
+    let mut idx = start_idx;
+    let mut read = |length: usize, missing: &'static str| {
+        let field = bits.get(idx..idx + length).ok_or((idx, missing))?;
+        idx += length;
+        Ok(field)
+    };
+    let number = |field: &str| usize::from_str_radix(field, 2).unwrap();
+
+    let version = number(read(3, "truncated packet header at")?);
+    let type_id = number(read(3, "truncated packet header at")?);
+    if type_id == 4 {
+        let mut value = "".to_string();
+        loop {
+            let group = read(5, "truncated literal at")?;
+            value += &group[1..];
+            if group.starts_with('0') {
+                break;
+            }
+        }
+        let value = from_bits(&value);
+        return Ok((Packet::Literal { version, value }, idx));
+    }
+
+    let mut packets = vec![];
+    if read(1, "missing sub-packet length at")? == "0" {
+        let length = number(read(15, "missing sub-packet length at")?);
+        let end = idx + length;
+        while idx < end {
+            let (packet, next) = decode(&bits[..end.min(bits.len())], idx)?;
+            packets.push(packet);
+            idx = next;
+        }
+    } else {
+        for _ in 0..number(read(11, "missing sub-packet count at")?) {
+            let (packet, next) = decode(bits, idx)?;
+            packets.push(packet);
+            idx = next;
+        }
+    }
+    if packets.is_empty() {
+        // points at the length type right after the header
+        return Err((start_idx + 6, "missing sub-packets at"));
+    }
+    Ok((
+        Packet::Operator {
+            version,
+            type_id,
+            packets,
+        },
+        idx,
+    ))
+}
+
+// a hex transmission per line, printed as a packet tree with its version sum and value
+fn repl() -> Box<dyn Repl> {
+    Box::new(|line: &Line| {
+        let packet = parse_line::<Day16>(line)?;
+        let value = evaluate(&packet).map_err(|e| line.error(line.text, e.to_string()))?;
+        Ok(format!(
+            "{}version sum {}, value {}",
+            packet,
+            version_sum(&packet),
+            value
+        ))
+    })
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "nested",
+        input: "A0016C880162017C3686B18A3D4780",
+        part1: Some("31"),
+        part2: None,
+    },
+    Example {
+        name: "two_operators",
+        input: "C0015000016115A2E0802F182340",
+        part1: Some("23"),
+        part2: None,
+    },
+    Example {
+        name: "sum",
+        input: "C200B40A82",
+        part1: None,
+        part2: Some("3"),
+    },
+    Example {
+        name: "product",
+        input: "04005AC33890",
+        part1: None,
+        part2: Some("54"),
+    },
+    Example {
+        name: "minimum",
+        input: "880086C3E88112",
+        part1: None,
+        part2: Some("7"),
+    },
+    Example {
+        name: "maximum",
+        input: "CE00C43D881120",
+        part1: None,
+        part2: Some("9"),
+    },
+    Example {
+        name: "less_than",
+        input: "D8005AC2A8F0",
+        part1: None,
+        part2: Some("1"),
+    },
+    Example {
+        name: "greater_than",
+        input: "F600BC2D8F",
+        part1: None,
+        part2: Some("0"),
+    },
+    Example {
+        name: "equal_to",
+        input: "9C005AC2F8F0",
+        part1: None,
+        part2: Some("0"),
+    },
+    Example {
+        name: "expression",
+        input: "9C0141080250320F1802104A08",
+        part1: None,
+        part2: Some("1"),
+    },
+];
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const REPL: Option<ReplFactory> = Some(repl);
+
+    type Input = Packet;
+    type Answer1 = usize;
+    type Answer2 = Outcome<Count, Overflow>;
+
+    fn parse(lines: &[String]) -> Result<Packet, ParseError> {
+        let line = input_line(Self::DAY, lines, 0)?;
+        if let Some(i) = line.text.find(|c: char| !c.is_ascii_hexdigit()) {
+            return Err(line.error(&line.text[i..i + 1], "not a hex digit"));
+        }
+        let mut bits = "".to_string();
+        for c in line.text.chars() {
+            let value = get_hex_value(c);
+            bits += format!("{:04b}", value).as_str();
+        }
+        // every hex digit is 4 bits, a problem past the last one points at the end
+        let (packet, _) = decode(&bits, 0).map_err(|(bit, message)| {
+            let digit = line.text.get(bit / 4..bit / 4 + 1).unwrap_or(line.end());
+            line.error(digit, message)
+        })?;
+        Ok(packet)
+    }
+
+    fn part1(packet: &Packet) -> usize {
+        version_sum(packet)
+    }
+
+    fn part2(packet: &Packet) -> Outcome<Count, Overflow> {
+        evaluate(packet).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day16>();
+
+        let literal = Day16::parse(&["D2FE28".to_string()]).unwrap();
+        assert_eq!(
+            literal,
+            Packet::Literal {
+                version: 6,
+                value: Ok(2021)
+            }
+        );
+        let sum = Day16::parse(&["C200B40A82".to_string()]).unwrap();
+        assert_eq!(sum.to_string(), "sum v6\n  1 v6\n  2 v2\n");
+        // a literal of 132 one bits doesn't fit any Count, the versions still add up
+        let huge =
+            Day16::parse(&["13FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFDE".to_string()]).unwrap();
+        assert_eq!(Day16::part1(&huge), 0);
+        assert_eq!(
+            Day16::part2(&huge).to_string(),
+            format!(
+                "no answer: `0b{}' overflows {}",
+                "1".repeat(132),
+                std::any::type_name::<Count>()
+            )
+        );
+
+        let errors = ["", "D2", "38006F452", "EE00", "C2000"]
+            .iter()
+            .map(|text| Day16::parse(&[text.to_string()]).unwrap_err().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                "day 16 line 1 column 1: truncated packet header at `'",
+                "day 16 line 1 column 2: truncated literal at `2'",
+                "day 16 line 1 column 10: truncated packet header at `'",
+                "day 16 line 1 column 2: missing sub-packet count at `E'",
+                "day 16 line 1 column 2: missing sub-packets at `2'",
+            ]
+        );
+    }
+}
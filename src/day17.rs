@@ -1,6 +1,18 @@
-use std::collections::HashSet;
+//! Day 17: Trick Shot
+
+use crate::parse::input_line;
+use crate::{Example, ParseError, Solution};
 use regex::Regex;
-use aoc_2021::read_lines_as_vec;
+use std::collections::HashSet;
+
+pub struct Day17;
+
+pub struct Target {
+    min_x: i32,
+    max_x: i32,
+    min_y: i32,
+    max_y: i32,
+}
 
 fn test_speed(
     x_speed: i32,
@@ -67,18 +79,12 @@ fn get_x_speed_list(min_x: i32, max_x: i32) -> HashSet<i32> {
     x_speed_list
 }
 
-fn part1(line: &String) -> ((i32, i32), i32) {
-    // 4851
-    let reg = Regex::new(r"-?\d+").unwrap();
-    let values = reg
-        .find_iter(line)
-        .map(|v| v.as_str().parse::<i32>().unwrap())
-        .collect::<Vec<_>>();
-
-    let min_x = values[0];
-    let max_x = values[1];
-    let min_y = values[2];
-    let max_y = values[3];
+/// The initial velocity reaching the highest position that still hits the target, and that height.
+pub fn highest_trajectory(target: &Target) -> ((i32, i32), i32) {
+    let min_x = target.min_x;
+    let max_x = target.max_x;
+    let min_y = target.min_y;
+    let max_y = target.max_y;
 
     let x_speed_list = get_x_speed_list(min_x, max_x);
 
@@ -89,11 +95,9 @@ fn part1(line: &String) -> ((i32, i32), i32) {
         let mut y_speed = 1;
         loop {
             let (hit, height_max) = test_speed(x_speed, y_speed, min_x, max_x, min_y, max_y);
-            if hit {
-                if height_max > height_max_all {
-                    height_max_all = height_max;
-                    height_max_speed = (x_speed, y_speed);
-                }
+            if hit && height_max > height_max_all {
+                height_max_all = height_max;
+                height_max_speed = (x_speed, y_speed);
             }
             y_speed += 1;
 
@@ -107,22 +111,16 @@ fn part1(line: &String) -> ((i32, i32), i32) {
     (height_max_speed, height_max_all)
 }
 
-fn part2(line: &String) -> usize {
-    // 1739
-    let reg = Regex::new(r"-?\d+").unwrap();
-    let values = reg
-        .find_iter(line)
-        .map(|v| v.as_str().parse::<i32>().unwrap())
-        .collect::<Vec<_>>();
-
-    let min_x = values[0];
-    let max_x = values[1];
-    let min_y = values[2];
-    let max_y = values[3];
+/// Number of distinct initial velocities that hit the target.
+pub fn count_trajectories(target: &Target) -> usize {
+    let min_x = target.min_x;
+    let max_x = target.max_x;
+    let min_y = target.min_y;
+    let max_y = target.max_y;
 
     // from part1 we know the speed_y for max height so we can assume
     // we dont need to test anything out of that range
-    let max_y_speed = part1(line).0.1 + 1;
+    let max_y_speed = highest_trajectory(target).0 .1 + 1;
 
     let x_speed_list = get_x_speed_list(min_x, max_x);
     let mut hit_list = HashSet::new();
@@ -138,28 +136,53 @@ fn part2(line: &String) -> usize {
     hit_list.len()
 }
 
-fn main() {
-    let lines = read_lines_as_vec("input/input_day17.txt").unwrap();
-    // let lines = vec!["target area: x=20..30, y=-10..-5"]
-    //     .iter()
-    //     .map(|s| s.to_string())
-    //     .collect::<Vec<_>>();
-    println!("{}", part1(&lines[0]).1);
-    println!("{}", part2(&lines[0]));
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "target area: x=20..30, y=-10..-5",
+    part1: Some("45"),
+    part2: Some("112"),
+}];
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = Target;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(lines: &[String]) -> Result<Target, ParseError> {
+        let reg = Regex::new(r"-?\d+").unwrap();
+        let line = input_line(Self::DAY, lines, 0)?;
+        let values = line.parse_all::<i32>(reg.find_iter(line.text).map(|v| v.as_str()))?;
+        if values.len() != 4 {
+            return Err(line.error(line.text, "expected x and y ranges in"));
+        }
+
+        Ok(Target {
+            min_x: values[0],
+            max_x: values[1],
+            min_y: values[2],
+            max_y: values[3],
+        })
+    }
+
+    fn part1(target: &Target) -> i32 {
+        highest_trajectory(target).1
+    }
+
+    fn part2(target: &Target) -> usize {
+        count_trajectories(target)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{part1, part2};
+    use super::*;
+    use crate::examples::check_examples;
 
     #[test]
     fn it_works() {
-        let lines = vec!["target area: x=20..30, y=-10..-5"]
-            .iter()
-            .map(|s| s.to_string())
-            .collect::<Vec<_>>();
-        assert_eq!(part1(&lines[0]).1, 45);
-        assert_eq!(part2(&lines[0]), 112);
-
+        check_examples::<Day17>();
     }
 }
@@ -1,8 +1,14 @@
+//! Day 18: Snailfish
+
+use crate::parse::{input_lines, Line};
+use crate::repl::{parse_line, Repl, ReplFactory};
+use crate::{Example, ParseError, Solution};
 use itertools::Itertools;
 use regex::Regex;
 use std::fmt::{Display, Formatter};
 use uuid::Uuid;
-use aoc_2021::read_lines_as_vec;
+
+pub struct Day18;
 
 macro_rules! is_of_var {
     ($val:ident, $var:path) => {
@@ -14,7 +20,7 @@ macro_rules! is_of_var {
 }
 
 #[derive(Debug, Clone)]
-struct List {
+pub struct List {
     items: Vec<Element>,
     uuid: Uuid,
 }
@@ -22,25 +28,14 @@ struct List {
 impl Display for List {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if self.items.len() == 1 {
-            match write!(f, "{}", self.items.first().unwrap()) {
-                Ok(_) => {}
-                Err(_) => {}
-            };
+            if write!(f, "{}", self.items.first().unwrap()).is_ok() {};
         } else {
-            let mut i = 0;
-            for element in self.items.iter() {
+            for (i, element) in self.items.iter().enumerate() {
                 if i == 0 {
-                    match write!(f, "{},", element) {
-                        Ok(_) => {}
-                        Err(_) => {}
-                    };
+                    if write!(f, "{},", element).is_ok() {};
                 } else {
-                    match write!(f, "{}", element) {
-                        Ok(_) => {}
-                        Err(_) => {}
-                    };
+                    if write!(f, "{}", element).is_ok() {};
                 }
-                i += 1;
             }
         }
         Ok(())
@@ -48,7 +43,7 @@ impl Display for List {
 }
 
 #[derive(Debug, Clone)]
-enum Element {
+pub enum Element {
     Integer(u32),
     List(Box<List>),
 }
@@ -69,8 +64,8 @@ impl Display for Element {
 impl PartialEq for Element {
     fn eq(&self, other: &Element) -> bool {
         match (self, other) {
-            (&Element::Integer(ref a), &Element::Integer(ref b)) => a == b,
-            (&Element::List(ref a), &Element::List(ref b)) => a == b,
+            (Element::Integer(a), Element::Integer(b)) => a == b,
+            (Element::List(a), Element::List(b)) => a == b,
             _ => false,
         }
     }
@@ -82,15 +77,31 @@ impl PartialEq for List {
     }
 }
 
+impl Default for List {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
 impl List {
-    fn new() -> Self {
+    /// An empty pair.
+    pub fn new() -> Self {
         List {
             items: vec![],
             uuid: Uuid::new_v4(),
         }
     }
 
-    fn new_with_values(left: u32, right: u32) -> Self {
+    /// The number wrapped as root, the form `reduce`, `explode` and `calc_magnitude` work on.
+    pub fn root(number: Element) -> Self {
+        List {
+            items: vec![number],
+            uuid: Uuid::new_v4(),
+        }
+    }
+
+    /// A pair of two regular numbers.
+    pub fn new_with_values(left: u32, right: u32) -> Self {
         List {
             items: vec![Element::Integer(left), Element::Integer(right)],
             uuid: Uuid::new_v4(),
@@ -172,18 +183,16 @@ impl List {
         self.items[1] = Element::List(Box::new(value))
     }
 
-    fn find(&self, uuid: Uuid) -> Option<&List> {
+    /// The pair with `uuid` below and including self.
+    pub fn find(&self, uuid: Uuid) -> Option<&List> {
         if self.uuid == uuid {
-            return Some(&self);
+            return Some(self);
         }
         for element in self.items.iter() {
-            match element {
-                Element::List(ref list) => {
-                    if let Some(l) = list.find(uuid) {
-                        return Some(l);
-                    }
+            if let Element::List(ref list) = element {
+                if let Some(l) = list.find(uuid) {
+                    return Some(l);
                 }
-                _ => {}
             }
         }
         None
@@ -197,13 +206,10 @@ impl List {
             return Some(self);
         }
         for element in self.items.iter_mut() {
-            match element {
-                Element::List(ref mut list) => {
-                    if let Some(l) = list.find_mut(uuid) {
-                        return Some(l);
-                    }
+            if let Element::List(ref mut list) = element {
+                if let Some(l) = list.find_mut(uuid) {
+                    return Some(l);
                 }
-                _ => {}
             }
         }
         None
@@ -212,16 +218,13 @@ impl List {
     // yes we dont need a parent ref/pointer/whatever
     fn parent(&self, uuid: Uuid) -> Option<&List> {
         for element in self.items.iter() {
-            match element {
-                Element::List(ref list) => {
-                    if list.uuid == uuid {
-                        return Some(&self);
-                    }
-                    if let Some(l) = list.parent(uuid) {
-                        return Some(l);
-                    }
+            if let Element::List(ref list) = element {
+                if list.uuid == uuid {
+                    return Some(self);
+                }
+                if let Some(l) = list.parent(uuid) {
+                    return Some(l);
                 }
-                _ => {}
             }
         }
         None
@@ -231,7 +234,7 @@ impl List {
         if self.items.len() == 1 {
             return false;
         }
-        let l = self.items.get(0).unwrap();
+        let l = self.items.first().unwrap();
         let r = self.items.get(1).unwrap();
 
         !is_of_var!(l, Element::Integer) && !is_of_var!(r, Element::Integer)
@@ -241,7 +244,7 @@ impl List {
         if self.items.len() == 1 {
             return false;
         }
-        let l = self.items.get(0).unwrap();
+        let l = self.items.first().unwrap();
         let r = self.items.get(1).unwrap();
 
         is_of_var!(l, Element::Integer) && is_of_var!(r, Element::Integer)
@@ -251,7 +254,7 @@ impl List {
         if self.items.len() == 1 {
             return false;
         }
-        let l = self.items.get(0).unwrap();
+        let l = self.items.first().unwrap();
         let r = self.items.get(1).unwrap();
 
         is_of_var!(l, Element::Integer) && !is_of_var!(r, Element::Integer)
@@ -261,24 +264,22 @@ impl List {
         if self.items.len() == 1 {
             return false;
         }
-        let l = self.items.get(0).unwrap();
+        let l = self.items.first().unwrap();
         let r = self.items.get(1).unwrap();
 
         !is_of_var!(l, Element::Integer) && is_of_var!(r, Element::Integer)
     }
 
-    fn get_first_pair(&self, level: usize) -> Option<Uuid> {
+    /// The leftmost pair of regular numbers nested inside four pairs, `level` is the depth of self.
+    pub fn get_first_pair(&self, level: usize) -> Option<Uuid> {
         for element in self.items.iter() {
-            match element {
-                Element::List(ref list) => {
-                    if list.is_integer_pair() && level == 4 {
-                        return Some(list.uuid);
-                    }
-                    if let Some(pair) = list.get_first_pair(level + 1) {
-                        return Some(pair);
-                    }
+            if let Element::List(ref list) = element {
+                if list.is_integer_pair() && level == 4 {
+                    return Some(list.uuid);
+                }
+                if let Some(pair) = list.get_first_pair(level + 1) {
+                    return Some(pair);
                 }
-                _ => {}
             }
         }
         None
@@ -294,7 +295,7 @@ impl List {
             // BUT we must search right down to get the closest to us
             if let Some(l) = self.left_list() {
                 if l.uuid != last.uuid {
-                    if let Some(res) = l.find_first_right_integer_down(root) {
+                    if let Some(res) = l.find_first_right_integer_down() {
                         return Some(res);
                     }
                 }
@@ -303,14 +304,14 @@ impl List {
         if let Some(parent) = root.parent(self.uuid) {
             if let Some(r) = parent.right_list() {
                 if r.uuid == self.uuid {
-                    if let Some(res) = parent.find_first_left_integer(root, &self) {
+                    if let Some(res) = parent.find_first_left_integer(root, self) {
                         return Some(res);
                     }
                 }
             }
             if let Some(l) = parent.left_list() {
                 if l.uuid == self.uuid {
-                    if let Some(res) = parent.find_first_left_integer(root, &self) {
+                    if let Some(res) = parent.find_first_left_integer(root, self) {
                         return Some(res);
                     }
                 }
@@ -319,24 +320,24 @@ impl List {
         None
     }
 
-    fn find_first_left_integer_down(&self, root: &List) -> Option<Uuid> {
+    fn find_first_left_integer_down(&self) -> Option<Uuid> {
         if self.is_integer_left() || self.is_integer_pair() {
             return Some(self.uuid);
         }
         if let Some(l) = self.left_list() {
-            if let Some(res) = l.find_first_left_integer_down(root) {
+            if let Some(res) = l.find_first_left_integer_down() {
                 return Some(res);
             }
         }
         None
     }
 
-    fn find_first_right_integer_down(&self, root: &List) -> Option<Uuid> {
+    fn find_first_right_integer_down(&self) -> Option<Uuid> {
         if self.is_integer_right() || self.is_integer_pair() {
             return Some(self.uuid);
         }
         if let Some(r) = self.right_list() {
-            if let Some(res) = r.find_first_right_integer_down(root) {
+            if let Some(res) = r.find_first_right_integer_down() {
                 return Some(res);
             }
         }
@@ -352,7 +353,7 @@ impl List {
             // BUT we must search left down to get the closest
             if let Some(r) = self.right_list() {
                 if r.uuid != last.uuid {
-                    if let Some(res) = r.find_first_left_integer_down(root) {
+                    if let Some(res) = r.find_first_left_integer_down() {
                         return Some(res);
                     }
                 }
@@ -361,14 +362,14 @@ impl List {
         if let Some(parent) = root.parent(self.uuid) {
             if let Some(r) = parent.right_list() {
                 if r.uuid == self.uuid {
-                    if let Some(res) = parent.find_first_right_integer(root, &self) {
+                    if let Some(res) = parent.find_first_right_integer(root, self) {
                         return Some(res);
                     }
                 }
             }
             if let Some(l) = parent.left_list() {
                 if l.uuid == self.uuid {
-                    if let Some(res) = parent.find_first_right_integer(root, &self) {
+                    if let Some(res) = parent.find_first_right_integer(root, self) {
                         return Some(res);
                     }
                 }
@@ -377,15 +378,16 @@ impl List {
         None
     }
 
-    fn explode(&mut self, pair: &List) {
+    /// Explodes `pair`, which must be a pair of regular numbers inside self.
+    pub fn explode(&mut self, pair: &List) {
         let left_value = pair.left_value().unwrap();
         let right_value = pair.right_value().unwrap();
 
-        let left_uuid = pair.find_first_left_integer(&self, pair);
-        let right_uuid = pair.find_first_right_integer(&self, pair);
+        let left_uuid = pair.find_first_left_integer(self, pair);
+        let right_uuid = pair.find_first_right_integer(self, pair);
 
-        if left_uuid.is_some() {
-            let left = self.find_mut(left_uuid.unwrap()).unwrap();
+        if let Some(left_uuid) = left_uuid {
+            let left = self.find_mut(left_uuid).unwrap();
 
             if left.is_integer_left() {
                 let left_add_value = left.left_value().unwrap();
@@ -397,8 +399,8 @@ impl List {
             }
         }
 
-        if right_uuid.is_some() {
-            let right = self.find_mut(right_uuid.unwrap()).unwrap();
+        if let Some(right_uuid) = right_uuid {
+            let right = self.find_mut(right_uuid).unwrap();
 
             if right.is_integer_right() {
                 let right_add_value = right.right_value().unwrap();
@@ -426,7 +428,8 @@ impl List {
         }
     }
 
-    fn can_split(&self) -> bool {
+    /// Any regular number of 10 or more.
+    pub fn can_split(&self) -> bool {
         for element in self.items.iter() {
             match element {
                 Element::List(ref list) => {
@@ -444,9 +447,9 @@ impl List {
         false
     }
 
-    fn split(&mut self) -> bool {
-        let mut i = 0;
-        for element in self.items.iter_mut() {
+    /// Splits the leftmost regular number of 10 or more, false if there is none.
+    pub fn split(&mut self) -> bool {
+        for (i, element) in self.items.iter_mut().enumerate() {
             match element {
                 Element::List(ref mut list) => {
                     if list.split() {
@@ -468,13 +471,13 @@ impl List {
                     }
                 }
             }
-            i += 1;
         }
 
         false
     }
 
-    fn calc_magnitude(&self) -> u32 {
+    /// Magnitude of the whole number.
+    pub fn calc_magnitude(&self) -> u32 {
         // root always is single item list
         if self.items.len() == 1 {
             let item = self.items.first().unwrap();
@@ -496,44 +499,50 @@ impl List {
     }
 }
 
-fn parse_term(line: &String, i: usize, current: &mut List) -> usize {
+fn parse_term(line: &Line, reg: &Regex, i: usize, current: &mut List) -> Result<usize, ParseError> {
+    let text = line.text;
     let mut i = i;
-    let current = current;
 
-    while i < line.len() {
-        let c = line.chars().nth(i).unwrap();
+    while i < text.len() {
+        let c = text[i..].chars().next().unwrap();
         if c == '[' {
             i += 1;
             let mut l = List::new();
-            i = parse_term(line, i, &mut l);
+            i = parse_term(line, reg, i, &mut l)?;
             current.items.push(Element::List(Box::new(l)));
         } else if c == ']' {
             i += 1;
-            return i;
+            return Ok(i);
         } else if c == ',' {
             i += 1;
+        } else if c.is_ascii_digit() {
+            let int_value_str = reg.find(&text[i..]).unwrap().as_str();
+            let int_value = Element::Integer(line.parse(int_value_str)?);
+            current.items.push(int_value);
+            i += int_value_str.len();
         } else {
-            let c = line.chars().nth(i).unwrap();
-            if c.is_digit(10) {
-                let reg = Regex::new(r"\d+").unwrap();
-                let int_value_str = reg.find(line.get(i..).unwrap()).unwrap().as_str();
-                let int_value = Element::Integer(int_value_str.parse().unwrap());
-                current.items.push(int_value);
-                i += 1;
-            }
+            return Err(line.error(&text[i..i + c.len_utf8()], "unexpected character"));
         }
     }
-    i
+    Ok(i)
 }
-fn reduce(r: &mut List) {
+/// Explodes and splits until the number is reduced.
+pub fn reduce(r: &mut List) {
+    reduce_with(r, |_, _| {})
+}
+
+/// Like `reduce`, `step` gets the action and the number after every explode and split.
+pub fn reduce_with(r: &mut List, mut step: impl FnMut(&str, &List)) {
     loop {
         if let Some(uuid) = r.get_first_pair(0) {
             let pair = r.find(uuid).unwrap().clone();
             r.explode(&pair);
+            step("explode", r);
             continue;
         } else {
             if r.can_split() {
                 r.split();
+                step("split", r);
                 continue;
             }
         }
@@ -541,65 +550,75 @@ fn reduce(r: &mut List) {
     }
 }
 
-fn calc_magnitude(left: &Element, right: &Element) -> u32 {
+/// The root of the pair of both numbers, not reduced yet.
+pub fn add(left: &Element, right: &Element) -> List {
     let mut root_list = List::new();
-    match left {
-        Element::List(ref l) => {
-            root_list.items.push(Element::List(l.clone()));
-        }
-        _ => {}
+    if let Element::List(ref l) = left {
+        root_list.items.push(Element::List(l.clone()));
     };
 
-    match right {
-        Element::List(ref r) => {
-            root_list.items.push(Element::List(r.clone()));
-        }
-        _ => {}
+    if let Element::List(ref r) = right {
+        root_list.items.push(Element::List(r.clone()));
     };
+    List::root(Element::List(Box::new(root_list)))
+}
 
-    let mut root_list2 = List::new();
-    root_list2
-        .items
-        .push(Element::List(Box::new(root_list.clone())));
-    let mut root = Some(root_list2);
-    let r = root.as_mut().unwrap();
-    reduce(r);
-    r.calc_magnitude()
+fn calc_magnitude(left: &Element, right: &Element) -> u32 {
+    let mut root = add(left, right);
+    reduce(&mut root);
+    root.calc_magnitude()
 }
 
-fn part1(lines: &[String]) -> u32 {
-    let mut root: Option<List> = None;
+// a number per line added to the sum of the lines before like the homework, every explode
+// and split is shown, `reset' starts a new sum
+fn repl() -> Box<dyn Repl> {
+    let mut sum: Option<Element> = None;
+    Box::new(move |line: &Line| {
+        if line.text == "reset" {
+            sum = None;
+            return Ok("sum cleared".to_string());
+        }
+        let number = parse_line::<Day18>(line)?.remove(0);
+        if let Element::Integer(_) = number {
+            return Err(line.error(line.text, "expected a pair, got a regular number"));
+        }
+        let mut root = match &sum {
+            Some(sum) => add(sum, &number),
+            None => List::root(number),
+        };
+
+        let mut steps = vec![];
+        reduce_with(&mut root, |action, number| {
+            steps.push(format!("{:<8} {}", action, number))
+        });
+        steps.push(format!("= {} magnitude {}", root, root.calc_magnitude()));
+        sum = root.items.pop();
+        Ok(steps.join("\n"))
+    })
+}
 
-    for line in lines.iter() {
-        let mut term = List::new();
-        parse_term(line, 0, &mut term);
-        let term_item = term.items.first().unwrap();
+/// Magnitude of the sum of all numbers in order.
+pub fn final_sum_magnitude(terms: &[Element]) -> u32 {
+    // [[[[7,7],[7,7]],[[0,8],[9,9]]],[[[6,6],[6,7]],[2,1]]] = 3359
+    let mut root: Option<List> = None;
 
+    for term_item in terms.iter() {
         if root.is_none() {
             let mut root_list = List::new();
-            match term_item {
-                Element::List(ref l) => {
-                    root_list.items.push(Element::List(l.clone()));
-                }
-                _ => {}
+            if let Element::List(ref l) = term_item {
+                root_list.items.push(Element::List(l.clone()));
             };
             root = Some(root_list);
             continue;
         }
-        let mut r = root.as_mut().unwrap();
+        let r = root.as_mut().unwrap();
         let mut root_list = List::new();
-        match r.items.first().unwrap() {
-            Element::List(ref l) => {
-                root_list.items.push(Element::List(l.clone()));
-            }
-            _ => {}
+        if let Element::List(ref l) = r.items.first().unwrap() {
+            root_list.items.push(Element::List(l.clone()));
         };
 
-        match term_item {
-            Element::List(ref r) => {
-                root_list.items.push(Element::List(r.clone()));
-            }
-            _ => {}
+        if let Element::List(ref r) = term_item {
+            root_list.items.push(Element::List(r.clone()));
         };
 
         let mut root_list2 = List::new();
@@ -616,17 +635,8 @@ fn part1(lines: &[String]) -> u32 {
     r.calc_magnitude()
 }
 
-fn part2(lines: &[String]) -> u32 {
-    // 4616
-    let mut terms = vec![];
-
-    for line in lines.iter() {
-        let mut term = List::new();
-        parse_term(line, 0, &mut term);
-        let term_item = term.items.first().unwrap();
-        terms.push(term_item.clone());
-    }
-
+/// Largest magnitude of the sum of any two different numbers.
+pub fn max_pair_magnitude(terms: &[Element]) -> u32 {
     let mut max_magnitude = 0;
 
     for pair in terms.iter().combinations(2) {
@@ -647,70 +657,81 @@ fn part2(lines: &[String]) -> u32 {
     max_magnitude
 }
 
-fn main() {
-    // [[[[7,7],[7,7]],[[0,8],[9,9]]],[[[6,6],[6,7]],[2,1]]] = 3359
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+    part1: Some("4140"),
+    part2: Some("3993"),
+}];
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const REPL: Option<ReplFactory> = Some(repl);
+
+    type Input = Vec<Element>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(lines: &[String]) -> Result<Vec<Element>, ParseError> {
+        let mut terms = vec![];
+        let reg = Regex::new(r"\d+").unwrap();
+
+        for line in input_lines(Self::DAY, lines) {
+            if line.text.matches('[').count() != line.text.matches(']').count() {
+                return Err(line.error(line.text, "unbalanced brackets in"));
+            }
+            let mut term = List::new();
+            parse_term(&line, &reg, 0, &mut term)?;
+            match term.items.first() {
+                Some(term_item) => terms.push(term_item.clone()),
+                None => return Err(line.error(line.text, "missing snailfish number")),
+            }
+        }
+        Ok(terms)
+    }
+
+    fn part1(terms: &Vec<Element>) -> u32 {
+        final_sum_magnitude(terms)
+    }
 
-    let lines = read_lines_as_vec("input/input_day18.txt").unwrap();
-
-    // let lines = vec![
-    //     "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
-    //     "[[[5,[2,8]],4],[5,[[9,9],0]]]",
-    //     "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
-    //     "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
-    //     "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
-    //     "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
-    //     "[[[[5,4],[7,7]],8],[[8,3],8]]",
-    //     "[[9,3],[[9,9],[6,[4,9]]]]",
-    //     "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
-    //     "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-    // ]
-    // .iter()
-    // .map(|s| s.to_string())
-    // .collect::<Vec<_>>();
-
-    // [[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]] - 3488
-    println!("{}", part1(&lines));
-    println!("{}", part2(&lines));
+    fn part2(terms: &Vec<Element>) -> u32 {
+        max_pair_magnitude(terms)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{part1, part2};
+    use super::*;
+    use crate::examples::check_examples;
 
     #[test]
     fn it_works() {
-        let lines = vec![
-            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
-            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
-            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
-            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
-            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
-            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
-            "[[[[5,4],[7,7]],8],[[8,3],8]]",
-            "[[9,3],[[9,9],[6,[4,9]]]]",
-            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
-            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-        ]
-        .iter()
-        .map(|s| s.to_string())
-        .collect::<Vec<_>>();
-        assert_eq!(part1(&lines), 4140);
-
-        let lines = vec![
-            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
-            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
-            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
-            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
-            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
-            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
-            "[[[[5,4],[7,7]],8],[[8,3],8]]",
-            "[[9,3],[[9,9],[6,[4,9]]]]",
-            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
-            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
-        ]
-        .iter()
-        .map(|s| s.to_string())
-        .collect::<Vec<_>>();
-        assert_eq!(part2(&lines), 3993);
+        check_examples::<Day18>();
+
+        let mut out = vec![];
+        let session = "[[[[4,3],4],4],[7,[[8,4],9]]]\n[1,1]\n";
+        crate::repl::run(18, repl().as_mut(), session.as_bytes(), &mut out, false).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "= [[[[4,3],4],4],[7,[[8,4],9]]] magnitude 1080
+explode  [[[[0,7],4],[7,[[8,4],9]]],[1,1]]
+explode  [[[[0,7],4],[15,[0,13]]],[1,1]]
+split    [[[[0,7],4],[[7,8],[0,13]]],[1,1]]
+split    [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]
+explode  [[[[0,7],4],[[7,8],[6,0]]],[8,1]]
+= [[[[0,7],4],[[7,8],[6,0]]],[8,1]] magnitude 1384
+"
+        );
     }
 }
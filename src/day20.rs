@@ -0,0 +1,243 @@
+//! Day 20: Trench Map
+
+use crate::grid::Pos;
+use crate::parse::{sections, Check, Line, Problems};
+use crate::pnm::Pnm;
+use crate::visualize::record;
+use crate::{Example, Grid, ParseError, Solution};
+
+pub struct Day20;
+
+pub struct Image {
+    algo_values: Vec<u8>,
+    grid: Grid<u8>,
+}
+
+fn render(grid: &Grid<u8>) -> String {
+    grid.map(|value| if *value == 1 { '#' } else { '.' })
+        .to_string()
+}
+
+fn get_neighbours_value(grid: &Grid<u8>, pos: Pos, default_value: u8) -> u16 {
+    let mut value_str = "".to_string();
+    for (x, y) in [
+        (pos.0 - 1, pos.1 - 1),
+        (pos.0, pos.1 - 1),
+        (pos.0 + 1, pos.1 - 1),
+        (pos.0 - 1, pos.1),
+        (pos.0, pos.1),
+        (pos.0 + 1, pos.1),
+        (pos.0 - 1, pos.1 + 1),
+        (pos.0, pos.1 + 1),
+        (pos.0 + 1, pos.1 + 1),
+    ]
+    .iter()
+    .map(|(r, c)| (*r, *c))
+    {
+        let value = *grid.get((x, y)).unwrap_or(&default_value);
+        value_str += value.to_string().as_str();
+    }
+    u16::from_str_radix(value_str.as_str(), 2).unwrap()
+}
+
+/// Number of lit pixels after enhancing `steps` times.
+pub fn enhance(image: &Image, steps: usize) -> usize {
+    enhanced(image, steps).values().filter(|v| *v == &1).count()
+}
+
+/// The image after enhancing `steps` times, 1 is a lit pixel.
+pub fn enhanced(image: &Image, steps: usize) -> Grid<u8> {
+    let algo_values = &image.algo_values;
+    let mut grid = image.grid.clone();
+    record("step 0", || render(&grid));
+
+    let init_enhance_padding_value = algo_values[0];
+    for step in 1..=steps {
+        // https://github.com/PhenixFine/advent-of-code-kotlin-2021/blob/main/src/Day20.kt
+        // the currently NOT visible part of the infinite input
+        // changes too so we MUST not add constant 0 padding
+        // but depending on algorithm what the invisible dark pixel
+        // would change too. This means its alternating between
+        // 0 and 1 to use for next padding round
+        // steps 1 - invisible pixel are 0
+        // enhance -> algo_values[0] == 1 so all that was 0 will become 1
+        // step 2 - invisible pixel are 1
+        // enhance -> algo_values[1] == 0 so all that was 1 will become 0
+        // step 3 - invisible pixel are 0
+
+        let padding_value = if init_enhance_padding_value == 0 {
+            0
+        } else if step % 2 == 0 {
+            1
+        } else {
+            0
+        };
+
+        // the image grows by one pixel on every side
+        grid = Grid::from_fn(grid.cols() + 2, grid.lines() + 2, |(x, y)| {
+            let value = get_neighbours_value(&grid, (x - 1, y - 1), padding_value);
+            algo_values[value as usize]
+        });
+        record(format!("step {}", step), || render(&grid));
+    }
+    grid
+}
+
+// every char that is neither # nor . is a problem
+fn read_pixels(line: &Line, problems: &mut Problems) -> Vec<u8> {
+    line.text
+        .char_indices()
+        .filter_map(|(i, c)| match c {
+            '#' => Some(1),
+            '.' => Some(0),
+            _ => {
+                problems.push(line.error(&line.text[i..i + c.len_utf8()], "expected # or ."));
+                None
+            }
+        })
+        .collect()
+}
+
+// the algorithm and the image, goes on after errors
+fn read(lines: &[String], problems: &mut Problems) -> Option<Image> {
+    let before = problems.len();
+    let sections = sections(Day20::DAY, lines);
+    problems.take(sections.expect(2));
+
+    // the algorithm may be wrapped over several lines
+    let mut algo_values = vec![];
+    if let Ok(algo) = sections.get(0) {
+        let mut length = 0;
+        for line in algo.lines.iter() {
+            algo_values.extend(read_pixels(line, problems));
+            length += line.text.chars().count();
+        }
+        if length != 512 {
+            let last = algo.lines.last().unwrap();
+            problems.push(last.error(last.end(), "expected 512 pixels in the algorithm"));
+        }
+    }
+
+    let image = sections.get(1).ok()?;
+    let cols = image.first().text.chars().count();
+    for line in image.lines.iter() {
+        read_pixels(line, problems);
+        match line.text.char_indices().nth(cols) {
+            Some((i, c)) => {
+                let extra = &line.text[i..i + c.len_utf8()];
+                problems.push(line.error(extra, format!("expected {} columns", cols)));
+            }
+            None if line.text.chars().count() < cols => {
+                problems.push(line.error(line.end(), format!("expected {} columns", cols)));
+            }
+            None => {}
+        }
+    }
+    if problems.len() > before {
+        return None;
+    }
+
+    let grid = Grid::from_lines(
+        image.lines.iter().copied(),
+        |c| match c {
+            '#' => Some(1),
+            '.' => Some(0),
+            _ => None,
+        },
+        "expected # or .",
+    );
+    Some(Image {
+        algo_values,
+        grid: problems.take(grid)?,
+    })
+}
+
+fn check(lines: &[String]) -> Vec<ParseError> {
+    Problems::all(|problems| read(lines, problems))
+}
+
+// the image of part 2, lit pixels are black
+fn dump(image: &Image) -> Pnm {
+    Pnm::Bitmap(enhanced(image, 50).map(|value| *value == 1))
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "example",
+        input: "\
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###",
+        part1: Some("35"),
+        part2: Some("3351"),
+    },
+];
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const DUMP: Option<fn(&Image) -> Pnm> = Some(dump);
+    const CHECK: Option<Check> = Some(check);
+
+    type Input = Image;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(lines: &[String]) -> Result<Image, ParseError> {
+        Problems::first(Self::DAY, |problems| read(lines, problems))
+    }
+
+    fn part1(image: &Image) -> usize {
+        enhance(image, 2)
+    }
+
+    fn part2(image: &Image) -> usize {
+        enhance(image, 50)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day20>();
+
+        // grows by a pixel on every side for each of the 50 steps
+        let example = Day20::parse(&EXAMPLES[0].lines()).unwrap();
+        let image = dump(&example);
+        assert!(image.to_string().starts_with("P1\n105 105\n"));
+        match image {
+            Pnm::Bitmap(lit) => {
+                assert_eq!(lit.values().filter(|lit| **lit).count(), 3351);
+                assert!(!lit[(0, 0)]);
+            }
+            other => panic!("expected a bitmap, got {:?}", other),
+        }
+
+        let lines = ["#.x.", "", "#.#", "#.", "##.#", "o.."]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let problems = check(&lines)
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            problems,
+            vec![
+                "day 20 section 1 line 1 column 3: expected # or . `x'",
+                "day 20 section 1 line 1 column 5: expected 512 pixels in the algorithm `'",
+                "day 20 section 2 line 4 column 3: expected 3 columns `'",
+                "day 20 section 2 line 5 column 4: expected 3 columns `#'",
+                "day 20 section 2 line 6 column 1: expected # or . `o'",
+            ]
+        );
+    }
+}
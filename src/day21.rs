@@ -1,7 +1,11 @@
-use itertools::Itertools;
+//! Day 21: Dirac Dice
+
+use crate::parse::input_line;
+use crate::{Example, ParseError, Solution};
 use regex::Regex;
 use std::collections::HashMap;
-use aoc_2021::read_lines_as_vec;
+
+pub struct Day21;
 
 fn get_next_dice(round: usize, dice_size: usize) -> Vec<usize> {
     let mut dice_values = vec![];
@@ -11,26 +15,10 @@ fn get_next_dice(round: usize, dice_size: usize) -> Vec<usize> {
     }
     dice_values
 }
-fn part1(lines: &[String]) -> usize {
-    // 1002474
-    let mut player1_pos = 0;
-    let mut player2_pos = 0;
-    let digits = Regex::new(r"\d+").unwrap();
-
-    player1_pos = digits
-        .find_iter(lines[0].as_str())
-        .nth(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
-    player2_pos = digits
-        .find_iter(lines[1].as_str())
-        .nth(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
+/// Losing score times number of rolls with the deterministic 100 sided die.
+pub fn deterministic_game(start: (u8, u8)) -> usize {
+    let mut player1_pos = start.0 as usize;
+    let mut player2_pos = start.1 as usize;
 
     let mut player1_score = 0;
     let mut player2_score = 0;
@@ -83,7 +71,7 @@ fn move_player(
     let mut victories = 0;
 
     for (game, count) in games.iter() {
-        let mut player = if player1 {
+        let player = if player1 {
             &game.player1
         } else {
             &game.player2
@@ -99,11 +87,11 @@ fn move_player(
             let new_state = if player1 {
                 Game {
                     player1: new_player,
-                    player2: game.player2.clone(),
+                    player2: game.player2,
                 }
             } else {
                 Game {
-                    player1: game.player1.clone(),
+                    player1: game.player1,
                     player2: new_player,
                 }
             };
@@ -122,24 +110,9 @@ fn move_player(
     (next_games, victories)
 }
 
-fn part2(lines: &[String]) -> u64 {
-    // 919758187195363
-    let digits = Regex::new(r"\d+").unwrap();
-
-    let player1_pos_start: u8 = digits
-        .find_iter(lines[0].as_str())
-        .nth(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
-    let player2_pos_start: u8 = digits
-        .find_iter(lines[1].as_str())
-        .nth(1)
-        .unwrap()
-        .as_str()
-        .parse()
-        .unwrap();
+/// Number of universes the player winning in more universes wins in.
+pub fn dirac_game(start: (u8, u8)) -> u64 {
+    let (player1_pos_start, player2_pos_start) = start;
 
     // https://www.ericburden.work/blog/2021/12/31/advent-of-code-2021-day-21/
 
@@ -173,7 +146,7 @@ fn part2(lines: &[String]) -> u64 {
 
     let mut player1_turn = true;
     while !games.is_empty() {
-        let mut victories = 0;
+        let victories;
         (games, victories) = move_player(&games, &frequencies_map, player1_turn);
         if player1_turn {
             win_map.entry("1").and_modify(|v| *v += victories);
@@ -186,33 +159,51 @@ fn part2(lines: &[String]) -> u64 {
     *win_map.values().max().unwrap()
 }
 
-fn main() {
-    let lines = read_lines_as_vec("input/input_day21.txt").unwrap();
-    // let lines = vec![
-    //     "Player 1 starting position: 4",
-    //     "Player 2 starting position: 8",
-    // ]
-    // .iter()
-    // .map(|s| s.to_string())
-    // .collect::<Vec<_>>();
-    println!("{}", part1(&lines));
-    println!("{}", part2(&lines));
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+Player 1 starting position: 4
+Player 2 starting position: 8",
+    part1: Some("739785"),
+    part2: Some("444356092776315"),
+}];
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+
+    type Input = (u8, u8);
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(lines: &[String]) -> Result<(u8, u8), ParseError> {
+        let digits = Regex::new(r"\d+").unwrap();
+        let start_pos = |index: usize| -> Result<u8, ParseError> {
+            let line = input_line(Self::DAY, lines, index)?;
+            match digits.find_iter(line.text).nth(1) {
+                Some(pos) => line.parse(pos.as_str()),
+                None => Err(line.error(line.end(), "missing starting position")),
+            }
+        };
+        Ok((start_pos(0)?, start_pos(1)?))
+    }
+
+    fn part1(start: &(u8, u8)) -> usize {
+        deterministic_game(*start)
+    }
+
+    fn part2(start: &(u8, u8)) -> u64 {
+        dirac_game(*start)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::part1;
+    use super::*;
+    use crate::examples::check_examples;
 
     #[test]
     fn it_works() {
-        let lines = vec![
-            "Player 1 starting position: 4",
-            "Player 2 starting position: 8",
-        ]
-        .iter()
-        .map(|s| s.to_string())
-        .collect::<Vec<_>>();
-        assert_eq!(part1(&lines), 739785);
-        assert_eq!(part1(&lines), 444356092776315);
+        check_examples::<Day21>();
     }
 }
@@ -0,0 +1,369 @@
+//! Day 22: Reactor Reboot
+
+use crate::generate::{Generator, Rng};
+use crate::parse::input_lines;
+use crate::{Example, ParseError, Solution};
+use glam::I64Vec3;
+use regex::Regex;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+pub struct Day22;
+
+#[derive(Debug, Clone)]
+pub struct RebootStep {
+    on: bool,
+    x_range: RangeInclusive<i64>,
+    y_range: RangeInclusive<i64>,
+    z_range: RangeInclusive<i64>,
+}
+
+/// Cubes on after the steps inside the -50..=50 initialization region, one voxel at a time.
+pub fn count_on_voxels(steps: &[RebootStep]) -> usize {
+    let mut core: HashMap<I64Vec3, bool> = HashMap::new();
+
+    for step in steps.iter() {
+        let x_range = &step.x_range;
+        let y_range = &step.y_range;
+        let z_range = &step.z_range;
+
+        if x_range.start() < &-50
+            || x_range.end() > &50
+            || y_range.start() < &-50
+            || y_range.end() > &50
+            || z_range.start() < &-50
+            || z_range.end() > &50
+        {
+            continue;
+        }
+
+        if step.on {
+            for x in x_range.clone() {
+                for y in y_range.clone() {
+                    for z in z_range.clone() {
+                        let pos = I64Vec3::new(x, y, z);
+                        core.insert(pos, true);
+                    }
+                }
+            }
+        } else {
+            for x in x_range.clone() {
+                for y in y_range.clone() {
+                    for z in z_range.clone() {
+                        let pos = I64Vec3::new(x, y, z);
+                        core.remove(&pos);
+                    }
+                }
+            }
+        }
+    }
+    core.len()
+}
+
+/// Overlap of a and b with the sign flipped to cancel b, None if they don't overlap.
+pub fn intersection(a: &MyCuboid, b: &MyCuboid) -> Option<MyCuboid> {
+    let intersection = MyCuboid::new(
+        max(a.min_x, b.min_x),
+        min(a.max_x, b.max_x),
+        max(a.min_y, b.min_y),
+        min(a.max_y, b.max_y),
+        max(a.min_z, b.min_z),
+        min(a.max_z, b.max_z),
+        -b.action,
+    );
+    if (intersection.min_x > intersection.max_x)
+        || (intersection.min_y > intersection.max_y)
+        || (intersection.min_z > intersection.max_z)
+    {
+        None
+    } else {
+        Some(intersection)
+    }
+}
+
+/// Axis aligned cuboid that is counted `action` times.
+#[derive(Debug, Clone)]
+pub struct MyCuboid {
+    min_x: i64,
+    max_x: i64,
+    min_y: i64,
+    max_y: i64,
+    min_z: i64,
+    max_z: i64,
+    action: i8,
+}
+
+impl MyCuboid {
+    /// From the inclusive ranges of a reboot step like `x=10..12`, `action` is 1 for on and
+    /// -1 to cancel an overlap.
+    pub fn new_from_range(
+        x_range: RangeInclusive<i64>,
+        y_range: RangeInclusive<i64>,
+        z_range: RangeInclusive<i64>,
+        action: i8,
+    ) -> Self {
+        MyCuboid {
+            min_x: *x_range.start(),
+            max_x: *x_range.end(),
+            min_y: *y_range.start(),
+            max_y: *y_range.end(),
+            min_z: *z_range.start(),
+            max_z: *z_range.end(),
+            action,
+        }
+    }
+    /// From the corners axis by axis, inclusive like the ranges, for cuboids computed from
+    /// others such as an intersection.
+    pub fn new(
+        min_x: i64,
+        max_x: i64,
+        min_y: i64,
+        max_y: i64,
+        min_z: i64,
+        max_z: i64,
+        action: i8,
+    ) -> Self {
+        MyCuboid {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+            min_z,
+            max_z,
+            action,
+        }
+    }
+    /// Signed number of cubes, negative for cancelling cuboids.
+    pub fn volume(&self) -> i64 {
+        let base_volume = (self.max_x - self.min_x + 1)
+            * (self.max_y - self.min_y + 1)
+            * (self.max_z - self.min_z + 1);
+
+        base_volume * self.action as i64
+    }
+}
+
+impl PartialEq for MyCuboid {
+    fn eq(&self, other: &Self) -> bool {
+        self.min_x == other.min_x
+            && self.max_x == other.max_x
+            && self.min_y == other.min_y
+            && self.max_y == other.max_y
+            && self.min_z == other.min_z
+            && self.max_z == other.max_z
+            && self.action == other.action
+    }
+}
+
+// https://github.com/nilanshu96/Advent-Of-Code/blob/main/2021/22/Part2.java
+// https://en.wikipedia.org/wiki/Inclusion%E2%80%93exclusion_principle
+/// Cubes on after all steps by inclusion-exclusion over the cuboids.
+pub fn count_on_cuboids(steps: &[RebootStep]) -> i64 {
+    let mut cubes = vec![];
+
+    for step in steps.iter() {
+        let action = if step.on { 1 } else { -1 };
+        let next_cube = MyCuboid::new_from_range(
+            step.x_range.clone(),
+            step.y_range.clone(),
+            step.z_range.clone(),
+            action,
+        );
+        // an off step must never be pushed on its own, not even as the first one
+        let mut new_cubes = cubes.clone();
+        for cube in cubes.iter() {
+            // intersection will get action of -cube.action
+            // so parts that have been marked as off below
+            // can be changed to on again in layers above
+            if let Some(intersection) = intersection(&next_cube, cube) {
+                new_cubes.push(intersection);
+            }
+        }
+        if action == 1 {
+            new_cubes.push(next_cube);
+        }
+        cubes.clear();
+        cubes.append(&mut new_cubes);
+    }
+    let v1 = cubes.iter().map(|c| c.volume()).sum();
+    v1
+}
+
+// size steps, like the real input the first half stays inside the -50..=50
+// initialization region and the rest spans huge cuboids
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|i| {
+            let (limit, extent) = if i < size / 2 {
+                (50, 30)
+            } else {
+                (100_000, 30_000)
+            };
+            let mut range = || {
+                let start = rng.range(-limit..=limit);
+                let end = (start + rng.range(0..=extent)).min(limit);
+                format!("{}..{}", start, end)
+            };
+            let (x, y, z) = (range(), range(), range());
+            let state = if i == 0 || rng.chance(70) {
+                "on"
+            } else {
+                "off"
+            };
+            format!("{} x={},y={},z={}", state, x, y, z)
+        })
+        .collect()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        name: "larger",
+        input: "\
+on x=-20..26,y=-36..17,z=-47..7
+on x=-20..33,y=-21..23,z=-26..28
+on x=-22..28,y=-29..23,z=-38..16
+on x=-46..7,y=-6..46,z=-50..-1
+on x=-49..1,y=-3..46,z=-24..28
+on x=2..47,y=-22..22,z=-23..27
+on x=-27..23,y=-28..26,z=-21..29
+on x=-39..5,y=-6..47,z=-3..44
+on x=-30..21,y=-8..43,z=-13..34
+on x=-22..26,y=-27..20,z=-29..19
+off x=-48..-32,y=26..41,z=-47..-37
+on x=-12..35,y=6..50,z=-50..-2
+off x=-48..-32,y=-32..-16,z=-15..-5
+on x=-18..26,y=-33..15,z=-7..46
+off x=-40..-22,y=-38..-28,z=23..41
+on x=-16..35,y=-41..10,z=-47..6
+off x=-32..-23,y=11..30,z=-14..3
+on x=-49..-5,y=-3..45,z=-29..18
+off x=18..30,y=-20..-8,z=-3..13
+on x=-41..9,y=-7..43,z=-33..15
+on x=-54112..-39298,y=-85059..-49293,z=-27449..7877
+on x=967..23432,y=45373..81175,z=27513..53682",
+        part1: Some("590784"),
+        part2: Some("39769202357779"),
+    },
+    Example {
+        name: "reboot",
+        input: include_str!("../input_test/input_test_day22.txt"),
+        part1: Some("474140"),
+        part2: Some("2758514936282235"),
+    },
+];
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input = Vec<RebootStep>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(lines: &[String]) -> Result<Vec<RebootStep>, ParseError> {
+        let reg = Regex::new(r"-?\d+").unwrap();
+        input_lines(Self::DAY, lines)
+            .map(|line| {
+                let (state, _) = line.split_once(" ")?;
+                if state != "on" && state != "off" {
+                    return Err(line.error(state, "expected on or off"));
+                }
+                let values = line.parse_all::<i64>(reg.find_iter(line.text).map(|v| v.as_str()))?;
+                if values.len() != 6 {
+                    return Err(line.error(line.text, "expected x, y and z ranges in"));
+                }
+                Ok(RebootStep {
+                    on: state == "on",
+                    x_range: values[0]..=values[1],
+                    y_range: values[2]..=values[3],
+                    z_range: values[4]..=values[5],
+                })
+            })
+            .collect()
+    }
+
+    fn part1(steps: &Vec<RebootStep>) -> usize {
+        count_on_voxels(steps)
+    }
+
+    fn part2(steps: &Vec<RebootStep>) -> i64 {
+        count_on_cuboids(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+    use crate::property::{check_agree, shrink_vec};
+
+    fn random_range(rng: &mut Rng) -> RangeInclusive<i64> {
+        let start = rng.range(-20..=20);
+        start..=start + rng.range(0..=8)
+    }
+
+    // shorter or closer to 0
+    fn shrink_range(range: &RangeInclusive<i64>) -> Vec<RangeInclusive<i64>> {
+        let (start, end) = (*range.start(), *range.end());
+        let mut candidates = vec![];
+        if end > start {
+            candidates.push(start..=start);
+            candidates.push(start..=end - 1);
+        }
+        if start > 0 {
+            candidates.push(start - 1..=end - 1);
+        }
+        if end < 0 {
+            candidates.push(start + 1..=end + 1);
+        }
+        candidates
+    }
+
+    fn shrink_step(step: &RebootStep) -> Vec<RebootStep> {
+        let mut candidates = vec![];
+        for x_range in shrink_range(&step.x_range) {
+            candidates.push(RebootStep {
+                x_range,
+                ..step.clone()
+            });
+        }
+        for y_range in shrink_range(&step.y_range) {
+            candidates.push(RebootStep {
+                y_range,
+                ..step.clone()
+            });
+        }
+        for z_range in shrink_range(&step.z_range) {
+            candidates.push(RebootStep {
+                z_range,
+                ..step.clone()
+            });
+        }
+        candidates
+    }
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day22>();
+
+        // inside the initialization region counting voxels is the ground truth
+        check_agree(
+            100,
+            |rng| {
+                (0..rng.below(8))
+                    .map(|_| RebootStep {
+                        on: rng.chance(60),
+                        x_range: random_range(rng),
+                        y_range: random_range(rng),
+                        z_range: random_range(rng),
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |steps| shrink_vec(steps, shrink_step),
+            |steps| count_on_voxels(steps) as i64,
+            |steps| count_on_cuboids(steps),
+        );
+    }
+}
@@ -0,0 +1,659 @@
+//! Day 23: Amphipod
+
+use crate::parse::{input_line, input_lines, Check, Line, Problems};
+use crate::shortest_path::dijkstra;
+use crate::{debug, trace, Example, Outcome, ParseError, Solution};
+use std::cmp::{Ordering, PartialEq};
+use std::collections::HashMap;
+use thiserror::Error;
+
+pub struct Day23;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum AntiPodType {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl TryFrom<&str> for AntiPodType {
+    type Error = ();
+
+    fn try_from(c: &str) -> Result<Self, Self::Error> {
+        match c {
+            "A" => Ok(AntiPodType::A),
+            "B" => Ok(AntiPodType::B),
+            "C" => Ok(AntiPodType::C),
+            "D" => Ok(AntiPodType::D),
+            _ => Err(()),
+        }
+    }
+}
+
+struct AntiPodRoom {
+    slot_pos: Vec<(usize, usize)>,
+    hallway_pos: Vec<(usize, usize)>,
+    forbidden_hallway_pos: Vec<(usize, usize)>,
+    slot_start: usize,
+    slot_height: usize,
+}
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+struct AntiPod {
+    anti_pod_type: AntiPodType,
+    pos: (usize, usize),
+}
+
+impl PartialOrd<Self> for AntiPod {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AntiPod {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_energy().cmp(&other.get_energy())
+    }
+}
+impl AntiPod {
+    fn get_energy(&self) -> usize {
+        match self.anti_pod_type {
+            AntiPodType::A => 1,
+            AntiPodType::B => 10,
+            AntiPodType::C => 100,
+            AntiPodType::D => 1000,
+        }
+    }
+    fn get_target_pos_x(&self) -> usize {
+        match self.anti_pod_type {
+            AntiPodType::A => 3,
+            AntiPodType::B => 5,
+            AntiPodType::C => 7,
+            AntiPodType::D => 9,
+        }
+    }
+    fn get_map_symbol(&self) -> char {
+        match self.anti_pod_type {
+            AntiPodType::A => 'A',
+            AntiPodType::B => 'B',
+            AntiPodType::C => 'C',
+            AntiPodType::D => 'D',
+        }
+    }
+
+    fn is_in_slot(&self, room: &AntiPodRoom) -> bool {
+        (room.slot_start..room.slot_start + room.slot_height).contains(&self.pos.1)
+    }
+
+    fn can_leave_slot(&self, anti_pods: &[AntiPod], room: &AntiPodRoom) -> bool {
+        (room.slot_start..self.pos.1)
+            .filter(|p| !is_free_on_map(self, anti_pods, (self.pos.0, *p)))
+            .count()
+            == 0
+    }
+
+    fn get_next_free_slot_pos(
+        &self,
+        anti_pods: &[AntiPod],
+        room: &AntiPodRoom,
+    ) -> Option<(usize, usize)> {
+        for y in room.slot_start..room.slot_start + room.slot_height {
+            if is_free_on_map(self, anti_pods, (self.get_target_pos_x(), y)) {
+                return Some((self.get_target_pos_x(), y));
+            }
+        }
+        None
+    }
+
+    fn is_in_correct_spot(&self, anti_pods: &[AntiPod], room: &AntiPodRoom) -> bool {
+        if self.pos.0 != self.get_target_pos_x() {
+            return false;
+        }
+
+        for y in room.slot_start..room.slot_start + room.slot_height {
+            let pos = (self.get_target_pos_x(), y);
+            if !is_free_on_map(self, anti_pods, pos)
+                && !is_used_by_type(self, self.anti_pod_type, anti_pods, pos)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn is_target_spot_free(&self, anti_pods: &[AntiPod], room: &AntiPodRoom) -> bool {
+        let next_free_slot = self.get_next_free_slot_pos(anti_pods, room);
+        if next_free_slot.is_none() {
+            return false;
+        }
+
+        for y in next_free_slot.unwrap().1..room.slot_start + room.slot_height {
+            let pos = (self.get_target_pos_x(), y);
+            if !is_free_on_map(self, anti_pods, pos)
+                && !is_used_by_type(self, self.anti_pod_type, anti_pods, pos)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    // empty list if currently not possible
+    fn get_moves_into_target_spot(
+        &self,
+        anti_pods: &[AntiPod],
+        room: &AntiPodRoom,
+    ) -> Vec<(usize, usize)> {
+        let mut moves = vec![];
+
+        if !self.is_target_spot_free(anti_pods, room) {
+            return vec![];
+        }
+
+        let next_free_slot = self.get_next_free_slot_pos(anti_pods, room);
+        if next_free_slot.is_none() {
+            // should not happen
+            return vec![];
+        }
+
+        if self.pos.0 > self.get_target_pos_x() {
+            for x in (self.get_target_pos_x()..=self.pos.0 - 1).rev() {
+                let pos = (x, 1);
+                if is_free_on_map(self, anti_pods, pos) {
+                    moves.push(pos)
+                } else {
+                    return vec![];
+                }
+            }
+        } else {
+            for x in self.pos.0 + 1..=self.get_target_pos_x() {
+                let pos = (x, 1);
+                if is_free_on_map(self, anti_pods, pos) {
+                    moves.push(pos)
+                } else {
+                    return vec![];
+                }
+            }
+        }
+
+        for y in room.slot_start..=next_free_slot.unwrap().1 {
+            moves.push((self.get_target_pos_x(), y));
+        }
+
+        moves
+    }
+
+    fn can_leave_slot_left(&self, anti_pods: &[AntiPod]) -> bool {
+        is_free_on_map(self, anti_pods, (self.pos.0 - 1, 1))
+    }
+
+    fn can_leave_slot_right(&self, anti_pods: &[AntiPod]) -> bool {
+        is_free_on_map(self, anti_pods, (self.pos.0 + 1, 1))
+    }
+
+    fn get_possible_moves(
+        &self,
+        anti_pods: &[AntiPod],
+        room: &AntiPodRoom,
+    ) -> Vec<Vec<(usize, usize)>> {
+        if self.is_in_correct_spot(anti_pods, room) {
+            return vec![];
+        }
+        let mut all_moves = vec![];
+
+        if self.is_on_hallway(room) {
+            let moves = self.get_moves_into_target_spot(anti_pods, room);
+            if !moves.is_empty() {
+                all_moves.push(moves);
+            }
+            return all_moves;
+        }
+
+        if self.is_in_slot(room) && self.can_leave_slot(anti_pods, room) {
+            // can only move out if left or right is free
+            if self.can_leave_slot_right(anti_pods) {
+                let mut moves = vec![];
+                // add steps out of slot
+                (room.slot_start..self.pos.1)
+                    .rev()
+                    .for_each(|p| moves.push((self.pos.0, p)));
+
+                // left or right
+                for x in self.pos.0..=11 {
+                    let pos = (x, 1);
+                    // can we step over forbidden slot
+                    if room.forbidden_hallway_pos.contains(&pos)
+                        && !is_free_on_map(self, anti_pods, (pos.0 + 1, 1))
+                    {
+                        break;
+                    }
+                    if is_free_on_map(self, anti_pods, pos) {
+                        moves.push(pos)
+                    }
+                }
+                if !moves.is_empty() {
+                    all_moves.push(moves);
+                }
+            }
+            if self.can_leave_slot_left(anti_pods) {
+                let mut moves = vec![];
+                // add steps out of slot
+                (room.slot_start..self.pos.1)
+                    .rev()
+                    .for_each(|p| moves.push((self.pos.0, p)));
+
+                for x in (1..=self.pos.0).rev() {
+                    let pos = (x, 1);
+                    if room.forbidden_hallway_pos.contains(&pos)
+                        && !is_free_on_map(self, anti_pods, (pos.0 - 1, 1))
+                    {
+                        break;
+                    }
+                    if is_free_on_map(self, anti_pods, pos) {
+                        moves.push(pos)
+                    }
+                }
+                if !moves.is_empty() {
+                    all_moves.push(moves);
+                }
+            }
+        }
+        all_moves
+    }
+
+    fn is_on_hallway(&self, room: &AntiPodRoom) -> bool {
+        room.hallway_pos.contains(&self.pos)
+    }
+}
+
+fn is_free_on_map(anti_pod: &AntiPod, anti_pods: &[AntiPod], pos: (usize, usize)) -> bool {
+    anti_pods
+        .iter()
+        .filter(|a| *a != anti_pod && a.pos == pos)
+        .count()
+        == 0
+}
+
+fn is_used_by_type(
+    anti_pod: &AntiPod,
+    anti_pod_type: AntiPodType,
+    anti_pods: &[AntiPod],
+    pos: (usize, usize),
+) -> bool {
+    anti_pods
+        .iter()
+        .filter(|a| *a != anti_pod && a.pos == pos && a.anti_pod_type == anti_pod_type)
+        .count()
+        == 1
+}
+
+fn render_map(anti_pods: &[AntiPod], anti_pod_room: &AntiPodRoom) -> String {
+    let mut s = String::new();
+    for y in 0..=2 + anti_pod_room.slot_height {
+        for x in 0..=12 {
+            let pos = (x, y);
+            if y == 0 {
+                s.push('#')
+            } else {
+                if let Some(anti_pod) = anti_pods
+                    .iter()
+                    .filter(|a| a.pos == pos)
+                    .collect::<Vec<_>>()
+                    .first()
+                {
+                    s.push(anti_pod.get_map_symbol());
+                } else if anti_pod_room.hallway_pos.contains(&pos)
+                    || anti_pod_room.slot_pos.contains(&pos)
+                {
+                    s.push('.')
+                } else {
+                    s.push('#')
+                }
+            }
+        }
+        s.push('\n');
+    }
+    s
+}
+
+// the positions along moves the antipod can stop at and the energy it takes to get there
+fn stops(
+    anti_pod: &AntiPod,
+    anti_pods: &[AntiPod],
+    moves: &[(usize, usize)],
+    room: &AntiPodRoom,
+) -> Vec<((usize, usize), i32)> {
+    let mut stops = vec![];
+    let m = anti_pod.pos;
+    let next_free_slot = anti_pod.get_next_free_slot_pos(anti_pods, room);
+    let mut length = 0;
+    for n in moves.iter() {
+        length += 1;
+
+        // dont add any edge that is not leaving the slot
+        let is_out_off_slot_move = (room.slot_start..room.slot_start + room.slot_height)
+            .contains(&m.1)
+            && n.1 == room.slot_start;
+
+        let mut is_in_slot_move = false;
+        // dont add any edge before final position
+        if n.0 == anti_pod.get_target_pos_x() {
+            if let Some(pos) = next_free_slot {
+                if n.1 != pos.1 {
+                    is_in_slot_move = true
+                }
+            }
+        }
+
+        if !room.forbidden_hallway_pos.contains(n) && !is_out_off_slot_move && !is_in_slot_move {
+            trace!(
+                "stop {:?} -> {:?} energy = {}",
+                m,
+                *n,
+                (anti_pod.get_energy() * length) as i32
+            );
+            stops.push((*n, (anti_pod.get_energy() * length) as i32));
+        }
+    }
+    stops
+}
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+struct State {
+    anti_pods: Vec<AntiPod>,
+}
+
+// every state reachable with one move of one antipod and the energy it takes
+fn next_states(state: &State, room: &AntiPodRoom) -> Vec<(State, i32)> {
+    let anti_pods = &state.anti_pods;
+    let mut states = vec![];
+
+    // the moves come straight from the paths of every antipod, no graph is built per state
+    for (index, a) in anti_pods.iter().enumerate() {
+        let moves = a.get_possible_moves(anti_pods, room);
+        trace!("anti_pod {:?} moves {:?}", a, moves);
+        for (pos, energy) in moves.iter().flat_map(|m| stops(a, anti_pods, m, room)) {
+            // move one antipod
+            let mut new_anti_pods = anti_pods.clone();
+            new_anti_pods[index].pos = pos;
+
+            let s_new = State {
+                anti_pods: new_anti_pods,
+            };
+            states.push((s_new, energy));
+        }
+    }
+    states
+}
+
+/// No order of moves gets every amphipod into its room.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
+#[error("the amphipods can't be organized")]
+pub struct Unsolvable;
+
+fn least_energy_to_solve(anti_pods: &[AntiPod], room: &AntiPodRoom) -> Result<i32, Unsolvable> {
+    let s = State {
+        anti_pods: anti_pods.to_vec(),
+    };
+    dijkstra(
+        s,
+        |state| next_states(state, room),
+        |state| is_solved(&state.anti_pods, room),
+    )
+    .map(|path| path.cost)
+    .ok_or(Unsolvable)
+}
+
+fn is_solved(anti_pods: &[AntiPod], room: &AntiPodRoom) -> bool {
+    anti_pods
+        .iter()
+        .filter(|a| a.is_in_correct_spot(anti_pods, room))
+        .count()
+        == anti_pods.len()
+}
+
+// the hallway and the rooms of the given depth
+fn burrow_room(slot_height: usize) -> AntiPodRoom {
+    let slot_start = 2;
+    AntiPodRoom {
+        slot_pos: [3, 5, 7, 9]
+            .iter()
+            .flat_map(|x| (slot_start..slot_start + slot_height).map(move |y| (*x, y)))
+            .collect(),
+        hallway_pos: (1..=11).map(|x| (x, 1)).collect(),
+        forbidden_hallway_pos: vec![(3, 1), (5, 1), (7, 1), (9, 1)],
+        slot_start,
+        slot_height,
+    }
+}
+
+/// The amphipods in the rooms of the part 1 burrow, the hallway starts empty.
+#[derive(Debug, Clone)]
+pub struct Burrow {
+    anti_pods: Vec<AntiPod>,
+}
+
+/// Least energy to organize the amphipods in the burrow.
+pub fn least_energy(burrow: &Burrow) -> Result<i32, Unsolvable> {
+    let room = burrow_room(2);
+    let mut antipods = burrow.anti_pods.clone();
+    antipods.sort();
+
+    least_energy_to_solve(&antipods, &room)
+}
+
+// the two lines unfolding inserts between the rooms of part 1
+const UNFOLDED: [[AntiPodType; 4]; 2] = [
+    [
+        AntiPodType::D,
+        AntiPodType::C,
+        AntiPodType::B,
+        AntiPodType::A,
+    ],
+    [
+        AntiPodType::D,
+        AntiPodType::B,
+        AntiPodType::A,
+        AntiPodType::C,
+    ],
+];
+
+/// Least energy with the two extra lines of the unfolded diagram.
+pub fn least_energy_unfolded(burrow: &Burrow) -> Result<i32, Unsolvable> {
+    let room = burrow_room(4);
+
+    // the bottom line of part 1 moves down below the inserted ones
+    let mut antipods = burrow
+        .anti_pods
+        .iter()
+        .map(|a| AntiPod {
+            pos: (a.pos.0, if a.pos.1 == 3 { 5 } else { a.pos.1 }),
+            ..*a
+        })
+        .collect::<Vec<_>>();
+    for (line, types) in UNFOLDED.iter().enumerate() {
+        for (slot, anti_pod_type) in types.iter().enumerate() {
+            antipods.push(AntiPod {
+                anti_pod_type: *anti_pod_type,
+                pos: (3 + 2 * slot, 3 + line),
+            });
+        }
+    }
+    antipods.sort();
+
+    debug!("start\n{}", render_map(&antipods, &room));
+
+    least_energy_to_solve(&antipods, &room)
+}
+
+// the burrow of part 1, an amphipod goes where the ? is
+const BURROW: [&str; 5] = [
+    "#############",
+    "#...........#",
+    "###?#?#?#?###",
+    "  #?#?#?#?#",
+    "  #########",
+];
+
+// the burrow with 2 amphipods of every type in the rooms and an empty hallway, goes on
+// after errors
+fn read(lines: &[String], problems: &mut Problems) -> Option<Burrow> {
+    let before = problems.len();
+    let mut amphipods: HashMap<char, Vec<(Line, &str)>> = HashMap::new();
+    let mut anti_pods = vec![];
+
+    for (line, expected) in input_lines(Day23::DAY, lines).zip(BURROW) {
+        let text = line.text.trim_end();
+        for (column, (i, c)) in text.char_indices().enumerate() {
+            let token = &text[i..i + c.len_utf8()];
+            let message = match expected.as_bytes().get(column) {
+                None => {
+                    let columns = expected.len();
+                    problems.push(line.error(token, format!("expected {} columns", columns)));
+                    break;
+                }
+                Some(b'?') if "ABCD".contains(c) => {
+                    amphipods.entry(c).or_default().push((line, token));
+                    if let Ok(anti_pod_type) = AntiPodType::try_from(token) {
+                        anti_pods.push(AntiPod {
+                            anti_pod_type,
+                            pos: (column, line.index),
+                        });
+                    }
+                    continue;
+                }
+                Some(b'?') => "expected an amphipod A, B, C or D",
+                Some(wall) if *wall as char == c => continue,
+                Some(b'#') => "expected a wall #",
+                Some(b'.') => "expected an open space .",
+                Some(_) => "expected a space",
+            };
+            problems.push(line.error(token, message));
+        }
+        if text.chars().count() < expected.len() {
+            let message = format!("expected {} columns", expected.len());
+            problems.push(line.error(line.end(), message));
+        }
+    }
+
+    let used = lines.iter().rposition(|line| !line.trim().is_empty());
+    match used {
+        Some(last) if last >= BURROW.len() => {
+            let extra = input_line(Day23::DAY, lines, BURROW.len()).unwrap();
+            problems.push(extra.error(extra.text, "expected the burrow to end, got"));
+        }
+        _ => {
+            if let Err(e) = input_line(Day23::DAY, lines, BURROW.len() - 1) {
+                problems.push(e);
+            }
+        }
+    }
+
+    if let Ok(room) = input_line(Day23::DAY, lines, 2) {
+        for kind in "ABCD".chars() {
+            let found = amphipods.get(&kind).map_or(&[][..], |found| &found[..]);
+            let message = format!("expected 2 amphipods of type {}, got {}", kind, found.len());
+            match found.get(2) {
+                Some((line, token)) => problems.push(line.error(token, message)),
+                None if found.len() < 2 => problems.push(room.error(room.text, message + " in")),
+                None => {}
+            }
+        }
+    }
+
+    (problems.len() == before).then_some(Burrow { anti_pods })
+}
+
+fn check(lines: &[String]) -> Vec<ParseError> {
+    Problems::all(|problems| read(lines, problems))
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########",
+    part1: Some("12521"),
+    part2: Some("44169"),
+}];
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const CHECK: Option<Check> = Some(check);
+
+    type Input = Burrow;
+    type Answer1 = Outcome<i32, Unsolvable>;
+    type Answer2 = Outcome<i32, Unsolvable>;
+
+    fn parse(lines: &[String]) -> Result<Burrow, ParseError> {
+        Problems::first(Self::DAY, |problems| read(lines, problems))
+    }
+
+    fn part1(burrow: &Burrow) -> Outcome<i32, Unsolvable> {
+        least_energy(burrow).into()
+    }
+
+    fn part2(burrow: &Burrow) -> Outcome<i32, Unsolvable> {
+        least_energy_unfolded(burrow).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day23>();
+
+        // D waits left of A in the hallway and neither can get past the other to its room
+        let stuck = [
+            ("A", (3, 3)),
+            ("B", (5, 2)),
+            ("B", (5, 3)),
+            ("C", (7, 2)),
+            ("C", (7, 3)),
+            ("D", (9, 3)),
+            ("D", (4, 1)),
+            ("A", (6, 1)),
+        ]
+        .map(|(kind, pos)| AntiPod {
+            anti_pod_type: AntiPodType::try_from(kind).unwrap(),
+            pos,
+        });
+        assert_eq!(
+            least_energy_to_solve(&stuck, &burrow_room(2)),
+            Err(Unsolvable)
+        );
+
+        let lines = [
+            "#############",
+            "#.....A.....#",
+            "###B#C#B#B###",
+            "  #A#D#C#A#",
+            "  ######",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+        let problems = check(&lines)
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            problems,
+            vec![
+                "day 23 line 2 column 7: expected an open space . `A'",
+                "day 23 line 3 column 1: expected 2 amphipods of type D, got 1 in `###B#C#B#B###'",
+                "day 23 line 3 column 10: expected 2 amphipods of type B, got 3 `B'",
+                "day 23 line 5 column 9: expected 11 columns `'",
+            ]
+        );
+    }
+}
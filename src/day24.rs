@@ -0,0 +1,787 @@
+//! Day 24: Arithmetic Logic Unit
+
+use crate::parse::{input_lines, Line};
+use crate::repl::{Repl, ReplFactory};
+use crate::{debug, trace, Example, ParseError, Part, Solution};
+use itertools::chain;
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
+
+pub struct Day24;
+
+#[derive(Debug, Eq, PartialEq)]
+enum InstructionType {
+    Inp,
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+impl TryFrom<&str> for InstructionType {
+    type Error = ();
+
+    fn try_from(c: &str) -> Result<Self, Self::Error> {
+        match c {
+            "inp" => Ok(InstructionType::Inp),
+            "add" => Ok(InstructionType::Add),
+            "mul" => Ok(InstructionType::Mul),
+            "div" => Ok(InstructionType::Div),
+            "mod" => Ok(InstructionType::Mod),
+            "eql" => Ok(InstructionType::Eql),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for InstructionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstructionType::Inp => write!(f, "inp"),
+            InstructionType::Add => write!(f, "add"),
+            InstructionType::Mul => write!(f, "mul"),
+            InstructionType::Div => write!(f, "div"),
+            InstructionType::Mod => write!(f, "mod"),
+            InstructionType::Eql => write!(f, "eql"),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Variable {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl TryFrom<&str> for Variable {
+    type Error = ();
+
+    fn try_from(c: &str) -> Result<Self, Self::Error> {
+        match c {
+            "x" => Ok(Variable::X),
+            "y" => Ok(Variable::Y),
+            "z" => Ok(Variable::Z),
+            "w" => Ok(Variable::W),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Variable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Variable::W => write!(f, "w"),
+            Variable::X => write!(f, "x"),
+            Variable::Y => write!(f, "y"),
+            Variable::Z => write!(f, "z"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    instruction_type: InstructionType,
+    variable_lhs: Variable,
+    variable_rhs: Option<Variable>,
+    value: Option<i64>,
+}
+
+impl Instruction {
+    fn new_inp(variable_lhs: Variable) -> Self {
+        Instruction {
+            instruction_type: InstructionType::Inp,
+            variable_lhs,
+            variable_rhs: None,
+            value: None,
+        }
+    }
+    fn new_add(variable_lhs: Variable, value: Option<i64>, variable_rhs: Option<Variable>) -> Self {
+        Instruction {
+            instruction_type: InstructionType::Add,
+            variable_lhs,
+            variable_rhs,
+            value,
+        }
+    }
+    fn new_mul(variable_lhs: Variable, value: Option<i64>, variable_rhs: Option<Variable>) -> Self {
+        Instruction {
+            instruction_type: InstructionType::Mul,
+            variable_lhs,
+            variable_rhs,
+            value,
+        }
+    }
+    fn new_div(variable_lhs: Variable, value: Option<i64>, variable_rhs: Option<Variable>) -> Self {
+        Instruction {
+            instruction_type: InstructionType::Div,
+            variable_lhs,
+            variable_rhs,
+            value,
+        }
+    }
+    fn new_mod(variable_lhs: Variable, value: Option<i64>, variable_rhs: Option<Variable>) -> Self {
+        Instruction {
+            instruction_type: InstructionType::Mod,
+            variable_lhs,
+            variable_rhs,
+            value,
+        }
+    }
+    fn new_eql(variable_lhs: Variable, value: Option<i64>, variable_rhs: Option<Variable>) -> Self {
+        Instruction {
+            instruction_type: InstructionType::Eql,
+            variable_lhs,
+            variable_rhs,
+            value,
+        }
+    }
+
+    // what the puzzle leaves undefined and would panic here
+    fn undefined(&self, storage: &VariableStorage) -> Option<&'static str> {
+        if self.instruction_type == InstructionType::Inp {
+            return None;
+        }
+        let lhs = storage.get_variable_value(&self.variable_lhs);
+        let rhs = self
+            .value
+            .unwrap_or_else(|| storage.get_variable_value(self.variable_rhs.as_ref().unwrap()));
+        match self.instruction_type {
+            InstructionType::Div if rhs == 0 => Some("division by zero in"),
+            InstructionType::Mod if lhs < 0 || rhs <= 0 => {
+                Some("mod of a negative number or by 0 in")
+            }
+            _ => None,
+        }
+    }
+
+    fn execute(
+        &self,
+        storage: &mut VariableStorage,
+        inp_values: &[i32],
+        inp_value_index: usize,
+    ) -> usize {
+        let mut inp_value_index = inp_value_index;
+        match self.instruction_type {
+            InstructionType::Inp => {
+                storage.set_variable_value(&self.variable_lhs, inp_values[inp_value_index] as i64);
+                inp_value_index += 1;
+
+                trace!("inp {} {}", inp_value_index, storage);
+            }
+            InstructionType::Add => {
+                if let Some(value) = self.value {
+                    let res = storage.get_variable_value(&self.variable_lhs) + value;
+                    storage.set_variable_value(&self.variable_lhs, res);
+                } else {
+                    let res = storage.get_variable_value(&self.variable_lhs)
+                        + storage.get_variable_value(self.variable_rhs.as_ref().unwrap());
+                    storage.set_variable_value(&self.variable_lhs, res);
+                }
+            }
+            InstructionType::Mul => {
+                if let Some(value) = self.value {
+                    let res = storage.get_variable_value(&self.variable_lhs) * value;
+                    storage.set_variable_value(&self.variable_lhs, res);
+                } else {
+                    let res = storage.get_variable_value(&self.variable_lhs)
+                        * storage.get_variable_value(self.variable_rhs.as_ref().unwrap());
+                    storage.set_variable_value(&self.variable_lhs, res);
+                }
+            }
+            InstructionType::Div => {
+                if let Some(value) = self.value {
+                    let res = storage.get_variable_value(&self.variable_lhs) / value;
+                    storage.set_variable_value(&self.variable_lhs, res);
+                } else {
+                    let res = storage.get_variable_value(&self.variable_lhs)
+                        / storage.get_variable_value(self.variable_rhs.as_ref().unwrap());
+                    storage.set_variable_value(&self.variable_lhs, res);
+                }
+            }
+            InstructionType::Mod => {
+                if let Some(value) = self.value {
+                    let res = storage
+                        .get_variable_value(&self.variable_lhs)
+                        .rem_euclid(value);
+                    storage.set_variable_value(&self.variable_lhs, res);
+                } else {
+                    let res = storage.get_variable_value(&self.variable_lhs).rem_euclid(
+                        storage.get_variable_value(self.variable_rhs.as_ref().unwrap()),
+                    );
+                    storage.set_variable_value(&self.variable_lhs, res);
+                }
+            }
+            InstructionType::Eql => {
+                if let Some(value) = self.value {
+                    let res = if storage.get_variable_value(&self.variable_lhs) == value {
+                        1
+                    } else {
+                        0
+                    };
+                    storage.set_variable_value(&self.variable_lhs, res);
+                } else {
+                    let res = if storage.get_variable_value(&self.variable_lhs)
+                        == storage.get_variable_value(self.variable_rhs.as_ref().unwrap())
+                    {
+                        1
+                    } else {
+                        0
+                    };
+                    storage.set_variable_value(&self.variable_lhs, res);
+                }
+            }
+        }
+        inp_value_index
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.value.is_none() && self.variable_rhs.is_none() {
+            return write!(f, "{} {}", self.instruction_type, self.variable_lhs);
+        }
+        if self.value.is_none() {
+            return write!(
+                f,
+                "{} {} {}",
+                self.instruction_type,
+                self.variable_lhs,
+                self.variable_rhs.as_ref().unwrap()
+            );
+        }
+        write!(
+            f,
+            "{} {} {}",
+            self.instruction_type,
+            self.variable_lhs,
+            self.value.as_ref().unwrap()
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+struct VariableStorage {
+    w: i64,
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl VariableStorage {
+    fn get_variable_value(&self, variable: &Variable) -> i64 {
+        match variable {
+            Variable::W => self.w,
+            Variable::X => self.x,
+            Variable::Y => self.y,
+            Variable::Z => self.z,
+        }
+    }
+
+    fn set_variable_value(&mut self, variable: &Variable, value: i64) {
+        match variable {
+            Variable::W => self.w = value,
+            Variable::X => self.x = value,
+            Variable::Y => self.y = value,
+            Variable::Z => self.z = value,
+        }
+    }
+}
+
+impl Display for VariableStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "w = {} x = {} y = {} z = {}",
+            self.w, self.x, self.y, self.z
+        )
+    }
+}
+
+fn convert_inp_value_to_number(inp_values: &[i32]) -> u64 {
+    inp_values
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |acc, (i, x)| acc + (*x as u64) * 10_u64.pow(i as u32))
+}
+
+fn run_program(program: &[Instruction], inp_values: &[i32]) -> VariableStorage {
+    let mut storage = VariableStorage {
+        w: 0,
+        x: 0,
+        y: 0,
+        z: 0,
+    };
+    let mut inp_value_index = 0;
+    for i in program.iter() {
+        trace!("{}", i);
+        inp_value_index = i.execute(&mut storage, inp_values, inp_value_index);
+        trace!("{}", storage);
+    }
+    debug!("end {}", storage);
+    storage
+}
+
+// the MONAD program is 14 blocks that only differ in three constants, `?' is any number
+//  4 div z (26|1)
+//  5 add x (-?[0-9]+)
+// 15 add y (-?[0-9]+)
+const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z ?", "add x ?", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y ?",
+    "mul y x", "add z y",
+];
+
+#[derive(Debug)]
+struct Block {
+    divisor: i32,
+    x_increment: i32,
+    y_increment: i32,
+}
+
+/// A program with the shape of MONAD, the only kind the model number can be solved for.
+#[derive(Debug)]
+pub struct Monad {
+    program: Vec<Instruction>,
+    blocks: Vec<Block>,
+}
+
+fn constant(line: &Line, instruction: &Instruction) -> Result<i32, ParseError> {
+    instruction
+        .value
+        .and_then(|value| i32::try_from(value).ok())
+        .ok_or_else(|| line.error(line.text, "expected a number that fits 32 bits in"))
+}
+
+// the digits of a model number, more wouldn't fit the u64 answer or z
+const DIGITS: usize = 14;
+
+// the constants of every block, a push (div z 1) has to be matched by a later pop (div z 26)
+// with digits from 1 to 9 that get z back to 0. a push always pushes w + y_increment as a
+// base 26 digit of z, so x_increment can't let eql x w hold and w + y_increment is from 0 to 25
+fn read_blocks(program: &[(Line, Instruction)]) -> Result<Vec<Block>, ParseError> {
+    let mut blocks = vec![];
+    let mut pushes: Vec<(&Line, i32)> = vec![];
+    for block in program.chunks(BLOCK.len()) {
+        if blocks.len() == DIGITS {
+            let (first, _) = &block[0];
+            let message = format!("expected at most {} MONAD blocks, got another at", DIGITS);
+            return Err(first.error(first.text, message));
+        }
+        for ((line, _), expected) in block.iter().zip(BLOCK) {
+            let matches = line.text.split(' ').count() == expected.split(' ').count()
+                && line
+                    .text
+                    .split(' ')
+                    .zip(expected.split(' '))
+                    .all(|(token, expected)| token == expected || expected == "?");
+            if !matches {
+                let message = format!("expected `{}' in a MONAD block, got", expected);
+                return Err(line.error(line.text, message));
+            }
+        }
+        if block.len() < BLOCK.len() {
+            let (last, _) = &block[block.len() - 1];
+            let message = format!(
+                "expected a MONAD block of {} instructions, it ends after {} with",
+                BLOCK.len(),
+                block.len()
+            );
+            return Err(last.error(last.text, message));
+        }
+
+        let (divisor_line, divisor) = &block[4];
+        let (x_line, x_increment) = &block[5];
+        let (y_line, y_increment) = &block[15];
+        let block = Block {
+            divisor: constant(divisor_line, divisor)?,
+            x_increment: constant(x_line, x_increment)?,
+            y_increment: constant(y_line, y_increment)?,
+        };
+        match block.divisor {
+            1 if block.x_increment < 10 => {
+                let message = "expected add x 10 or more in a push block, got";
+                return Err(x_line.error(x_line.text, message));
+            }
+            1 if !(-1..=16).contains(&block.y_increment) => {
+                let message = "expected add y from -1 to 16 in a push block, got";
+                return Err(y_line.error(y_line.text, message));
+            }
+            1 => pushes.push((y_line, block.y_increment)),
+            26 => {
+                let (push, y_increment) = pushes
+                    .pop()
+                    .ok_or_else(|| divisor_line.error(divisor_line.text, "no push to pop in"))?;
+                if (i64::from(y_increment) + i64::from(block.x_increment)).abs() > 8 {
+                    let message = format!(
+                        "no digits from 1 to 9 match the push on line {} with",
+                        push.number()
+                    );
+                    return Err(x_line.error(x_line.text, message));
+                }
+            }
+            _ => return Err(divisor_line.error(divisor_line.text, "expected div z 1 or 26, got")),
+        }
+        blocks.push(block);
+    }
+    if let Some((push, _)) = pushes.last() {
+        return Err(push.error(push.text, "the push of this block is never popped"));
+    }
+    Ok(blocks)
+}
+
+// https://github.com/MarcelRobitaille/2021-advent-of-code/blob/main/day_24/src/main.rs
+// https://www.ericburden.work/blog/2022/01/05/advent-of-code-2021-day-24/
+
+// This problem is similar to a stack machine, as discussed on the advent of code subreddit. A
+// "push" occurs anytime the divisor is 1. A "pop" occurs anytime the divisor is 26. In the latter
+// case, w must be equal to z % 26 + x_increment. Therefore, we must push a value in the former
+// case to make this satisfiable.
+//
+// My solution assumes that every push operation has a matching pop operation. This means a push
+// could be followed by a pop, two pushes could be folioed by two pops, and so on. The recurse
+// function will match pushes to pops, processing any nested operations, and the remaining,
+// following operations as appropriate
+//
+// For example, here are the calls to recurse for my input. The push and pop for one recurse are
+// marked, and nested instructions are indented
+// Recurse
+// ├ { divisor: 1, x_increment: 10, y_increment: 13 }
+// │ Recurse
+// │ ├ { divisor: 1, x_increment: 13, y_increment: 10 }
+// │ │ Recurse
+// │ │ ├ { divisor: 1, x_increment: 13, y_increment: 3 }
+// │ │ └ { divisor: 26, x_increment: -11, y_increment: 1 }
+// │ │ Recurse
+// │ │ ├ { divisor: 1, x_increment: 11, y_increment: 9 }
+// │ │ └ { divisor: 26, x_increment: -4, y_increment: 3 }
+// │ │ Recurse
+// │ │ ├ { divisor: 1, x_increment: 12, y_increment: 5 }
+// │ │ │ Recurse
+// │ │ │ ├ { divisor: 1, x_increment: 12, y_increment: 1 }
+// │ │ │ │ Recurse
+// │ │ │ │ ├ { divisor: 1, x_increment: 15, y_increment: 0 }
+// │ │ │ │ └ { divisor: 26, x_increment: -2, y_increment: 13 }
+// │ │ │ └ { divisor: 26, x_increment: -5, y_increment: 7 }
+// │ │ └ { divisor: 26, x_increment: -11, y_increment: 15 }
+// │ └ { divisor: 26, x_increment: -13, y_increment: 12 }
+// └ { divisor: 26, x_increment: -10, y_increment: 8 }
+//
+// Once pushes and pops are paired, I can calculate the maximum (or minimum, for part 2) value of
+// the left digit that satisfies the range of the right digit. This is similar to the stack machine
+// that I have seen many discussions about, but uses recursion rather than an actual stack (well,
+// it uses the call stack). I first did a solution using a stack, but pushing a value onto the
+// stack requires future information, which is why I pair up a push and a pop and calculate the
+// value for both at once.
+
+fn recurse(blocks: &[Block], question_part: Part) -> (&[Block], Vec<i32>) {
+    // If we've exhausted input or we get to somebody's right hand side,
+    // stop recursing
+    if blocks.is_empty() || blocks[0].divisor == 26 {
+        return (blocks, vec![]);
+    }
+
+    // Get the left instruction (divisor = 1)
+    let (left, blocks) = blocks.split_first().unwrap();
+
+    // Parse all the instructions in between this pair
+    let (blocks, mid) = recurse(blocks, question_part);
+
+    // Get the right instruction (divisor = 26)
+    let (right, blocks) = blocks.split_first().unwrap();
+
+    let left_output = match question_part {
+        // Calculate the maximum value the left digit can be without making the right value go over
+        // 9
+        Part::One => min(9, 9 - left.y_increment - right.x_increment),
+        // Calculate the minimum value the left digit can be in the same way
+        Part::Two => max(1, 1 - left.y_increment - right.x_increment),
+    };
+    // Calculate right digit based on left digit, left y-increment, and right x-increment
+    let right_output = left_output + left.y_increment + right.x_increment;
+
+    // Get digits from the remainder of the input
+    let (blocks, tail) = recurse(blocks, question_part);
+
+    // Chain left, mid, right, and remainder
+    (
+        blocks,
+        chain!([left_output], mid, [right_output], tail).collect::<Vec<i32>>(),
+    )
+}
+
+/// Largest model number for part 1, smallest for part 2, accepted by MONAD.
+pub fn find_model_number(monad: &Monad, question_part: Part) -> u64 {
+    // parse made sure every push has its pop and always pushes
+    let (_, inp_values) = recurse(&monad.blocks, question_part);
+
+    // double check with the ALU that MONAD accepts it, the checks of parse make sure it does
+    let storage = run_program(&monad.program, &inp_values);
+    assert_eq!(storage.z, 0);
+
+    convert_inp_value_to_number(&inp_values)
+}
+
+fn parse_instruction(line: &Line) -> Result<Instruction, ParseError> {
+    let parts = line.text.split(' ').collect::<Vec<_>>();
+
+    let instruction_type = InstructionType::try_from(parts[0])
+        .map_err(|_| line.error(parts[0], "unknown instruction"))?;
+    let operands = if instruction_type == InstructionType::Inp {
+        1
+    } else {
+        2
+    };
+    if parts.len() != operands + 1 {
+        return Err(line.error(line.text, format!("expected {} operands in", operands)));
+    }
+    let variable = |token: &str| {
+        Variable::try_from(token).map_err(|_| line.error(token, "expected one of w, x, y, z"))
+    };
+
+    let variable_lhs = variable(parts[1])?;
+    let mut value: Option<i64> = None;
+    let mut variable_rhs: Option<Variable> = None;
+
+    if parts.len() == 3 {
+        if let Ok(v) = parts[2].parse() {
+            value = Some(v);
+        } else {
+            variable_rhs = Some(variable(parts[2])?);
+        }
+    }
+    Ok(match instruction_type {
+        InstructionType::Inp => Instruction::new_inp(variable_lhs),
+        InstructionType::Add => Instruction::new_add(variable_lhs, value, variable_rhs),
+        InstructionType::Mul => Instruction::new_mul(variable_lhs, value, variable_rhs),
+        InstructionType::Div => Instruction::new_div(variable_lhs, value, variable_rhs),
+        InstructionType::Mod => Instruction::new_mod(variable_lhs, value, variable_rhs),
+        InstructionType::Eql => Instruction::new_eql(variable_lhs, value, variable_rhs),
+    })
+}
+
+// any ALU program, empty lines and comments are skipped
+fn read_program(day: u8, lines: &[String]) -> Result<Vec<(Line<'_>, Instruction)>, ParseError> {
+    input_lines(day, lines)
+        .filter(|line| !line.text.is_empty() && !line.text.starts_with('#'))
+        .map(|line| Ok((line, parse_instruction(&line)?)))
+        .collect()
+}
+
+/// Any ALU program, MONAD or not, for running it with [`run_program`].
+pub fn parse_program(lines: &[String]) -> Result<Vec<Instruction>, ParseError> {
+    let program = read_program(Day24::DAY, lines)?;
+    Ok(program
+        .into_iter()
+        .map(|(_, instruction)| instruction)
+        .collect())
+}
+
+// the registers after every instruction, `inp' reads the value from the end of the line
+// like `inp w 7' and `reset' sets all registers back to 0
+#[derive(Default)]
+struct Alu {
+    storage: VariableStorage,
+}
+
+impl Repl for Alu {
+    fn eval(&mut self, line: &Line) -> Result<String, ParseError> {
+        if line.text == "reset" {
+            self.storage = VariableStorage::default();
+            return Ok(self.storage.to_string());
+        }
+        let parts = line.text.split(' ').collect::<Vec<_>>();
+        let (line, input) = match parts[..] {
+            ["inp", _] => return Err(line.error(line.text, "missing the input value in")),
+            ["inp", _, value] => {
+                let text = line.text[..line.text.len() - value.len()].trim_end();
+                (Line { text, ..*line }, Some(line.parse::<i32>(value)?))
+            }
+            _ => (*line, None),
+        };
+
+        let instruction = parse_instruction(&line)?;
+        if let Some(message) = instruction.undefined(&self.storage) {
+            return Err(line.error(line.text, message));
+        }
+        instruction.execute(&mut self.storage, &input.into_iter().collect::<Vec<_>>(), 0);
+        Ok(self.storage.to_string())
+    }
+}
+
+// a push and its pop, the second digit is one more than the first
+const EXAMPLES: &[Example] = &[Example {
+    name: "two blocks",
+    input: "\
+inp w
+mul x 0
+add x z
+mod x 26
+div z 1
+add x 11
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y 3
+mul y x
+add z y
+inp w
+mul x 0
+add x z
+mod x 26
+div z 26
+add x -2
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y 5
+mul y x
+add z y",
+    part1: Some("89"),
+    part2: Some("12"),
+}];
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const REPL: Option<ReplFactory> = Some(|| Box::<Alu>::default());
+
+    type Input = Monad;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(lines: &[String]) -> Result<Monad, ParseError> {
+        let program = read_program(Self::DAY, lines)?;
+        let blocks = read_blocks(&program)?;
+        Ok(Monad {
+            program: program
+                .into_iter()
+                .map(|(_, instruction)| instruction)
+                .collect(),
+            blocks,
+        })
+    }
+
+    fn part1(monad: &Monad) -> u64 {
+        find_model_number(monad, Part::One)
+    }
+
+    fn part2(monad: &Monad) -> u64 {
+        find_model_number(monad, Part::Two)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        let lines = [
+            "inp w", "add z w", "mod z 2", "div w 2", "add y w", "mod y 2", "div w 2", "add x w",
+            "mod x 2", "div w 2", "mod w 2",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+
+        let program = parse_program(&lines).unwrap();
+        let storage = run_program(&program, &[13]);
+        assert_eq!((storage.w, storage.x, storage.y, storage.z), (1, 1, 0, 1));
+        let err = Day24::parse(&lines).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 2 column 1: expected `mul x 0' in a MONAD block, got `add z w'"
+        );
+
+        let monad = |constants: &[(i32, i32, i32)]| {
+            constants
+                .iter()
+                .flat_map(|(divisor, x, y)| {
+                    BLOCK.iter().enumerate().map(move |(i, line)| match i {
+                        4 => format!("div z {}", divisor),
+                        5 => format!("add x {}", x),
+                        15 => format!("add y {}", y),
+                        _ => line.to_string(),
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        let lines = monad(&[(1, 11, 3), (26, -2, 5)]);
+        assert_eq!(lines, EXAMPLES[0].lines());
+        check_examples::<Day24>();
+        let err = Day24::parse(&monad(&[(1, 11, 3), (1, 12, 5)])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 34 column 1: the push of this block is never popped `add y 5'"
+        );
+        let err = Day24::parse(&monad(&[(1, 11, 3), (26, 9, 5)])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 24 column 1: no digits from 1 to 9 match the push on line 16 with `add x 9'"
+        );
+        // eql x w could hold and skip the push
+        let err = Day24::parse(&monad(&[(1, 5, 3), (26, -2, 5)])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 6 column 1: expected add x 10 or more in a push block, got `add x 5'"
+        );
+        // w + 20 is more than a base 26 digit
+        let err = Day24::parse(&monad(&[(1, 11, 20), (26, -2, 5)])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 16 column 1: expected add y from -1 to 16 in a push block, got `add y 20'"
+        );
+        let err = Day24::parse(&monad(&[(1, 11, 3), (26, -2, 5)].repeat(8))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 253 column 1: expected at most 14 MONAD blocks, got another at `inp w'"
+        );
+        let err = Day24::parse(&lines[..20]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 20 column 1: expected a MONAD block of 18 instructions, it ends after 2 with `mul x 0'"
+        );
+
+        let lines = ["inp w", "sub z w"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let err = Day24::parse(&lines).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24 line 2 column 1: unknown instruction `sub'"
+        );
+
+        let mut out = vec![];
+        let session = "inp w 7\nmul w -2\nmod w 3\nadd z w\ninp x\ndiv z x\nreset\n";
+        crate::repl::run(24, &mut Alu::default(), session.as_bytes(), &mut out, false).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "w = 7 x = 0 y = 0 z = 0
+w = -14 x = 0 y = 0 z = 0
+day 24 line 3 column 1: mod of a negative number or by 0 in `mod w 3'
+w = -14 x = 0 y = 0 z = -14
+day 24 line 5 column 1: missing the input value in `inp x'
+day 24 line 6 column 1: division by zero in `div z x'
+w = 0 x = 0 y = 0 z = 0
+"
+        );
+    }
+}
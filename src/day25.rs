@@ -0,0 +1,228 @@
+//! Day 25: Sea Cucumber
+
+use crate::generate::{Generator, Rng};
+use crate::pnm::Pnm;
+use crate::simulate::{simulate, Cycled, Outcome as Ending, Stop};
+use crate::visualize::{is_recording, record};
+use crate::{debug, Example, Grid, Outcome, ParseError, Part, Solution};
+use std::fmt::{Display, Formatter};
+
+pub struct Day25;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CucumberType {
+    East,
+    South,
+}
+impl TryFrom<char> for CucumberType {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '>' => Ok(CucumberType::East),
+            'v' => Ok(CucumberType::South),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for CucumberType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CucumberType::East => write!(f, ">"),
+            CucumberType::South => write!(f, "v"),
+        }
+    }
+}
+
+/// The map, wrapping around at the edges.
+pub type SeaFloor = Grid<Option<CucumberType>>;
+
+// moves every cucumber of the herd that has a free cell in front of it
+fn move_herd(map: &SeaFloor, herd: CucumberType) -> SeaFloor {
+    let direction = match herd {
+        CucumberType::East => (1, 0),
+        CucumberType::South => (0, 1),
+    };
+    let mut next = map.clone();
+    for (pos, _) in map.iter().filter(|(_, cell)| **cell == Some(herd)) {
+        // the sea floor wraps so there always is a next position
+        let next_pos = map.step(pos, direction).unwrap();
+        if map[next_pos].is_none() {
+            next[pos] = None;
+            next[next_pos] = Some(herd);
+        }
+    }
+    next
+}
+
+fn render(map: &SeaFloor) -> String {
+    map.map(|cell| cell.map_or(".".to_string(), |c| c.to_string()))
+        .to_string()
+}
+
+/// First step on which no sea cucumber moves, or the cycle the herds go round in when a
+/// cucumber can keep wrapping around forever.
+pub fn steps_until_stuck(sea_floor: &SeaFloor) -> Result<usize, Cycled> {
+    run_until_stuck(sea_floor).0
+}
+
+/// The map once no sea cucumber can move anymore.
+pub fn stuck_map(sea_floor: &SeaFloor) -> Result<SeaFloor, Cycled> {
+    let (steps, map) = run_until_stuck(sea_floor);
+    steps.map(|_| map)
+}
+
+// the steps until stuck and the stuck map, or the cycle and the map it starts with
+fn run_until_stuck(sea_floor: &SeaFloor) -> (Result<usize, Cycled>, SeaFloor) {
+    record("step 0", || render(sea_floor));
+
+    let stuck = simulate(
+        sea_floor.clone(),
+        |map| move_herd(&move_herd(map, CucumberType::East), CucumberType::South),
+        Stop::Fixpoint,
+    );
+    if let Ending::Cycle { start, period } = stuck.outcome {
+        return (Err(Cycled { start, period }), stuck.state);
+    }
+
+    // simulate may run a step twice, the frames come from a run of their own
+    if is_recording() {
+        let mut map = sea_floor.clone();
+        for n in 1..=stuck.steps + 1 {
+            map = move_herd(&map, CucumberType::East);
+            record(format!("step {} east", n), || render(&map));
+            map = move_herd(&map, CucumberType::South);
+            record(format!("step {} south", n), || render(&map));
+        }
+    }
+    // the step that didn't move anything comes after the stuck state
+    debug!("stuck after {} steps", stuck.steps + 1);
+    (Ok(stuck.steps + 1), stuck.state)
+}
+
+// the stuck herds, or the first map of the cycle they go round in, east facing cucumbers
+// black, south facing ones gray and empty cells white
+fn dump(sea_floor: &SeaFloor) -> Pnm {
+    let grid = run_until_stuck(sea_floor).1.map(|cell| match cell {
+        Some(CucumberType::East) => 0,
+        Some(CucumberType::South) => 1,
+        None => 2,
+    });
+    Pnm::Graymap { grid, max: 2 }
+}
+
+// a size x size map. column 0 is all south and row 0 all east facing cucumbers,
+// neither can ever move and nothing can wrap around past them, so every
+// cucumber only has finitely many moves and the herd is guaranteed to get stuck
+fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(2);
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| match (x, y) {
+                    (0, _) => 'v',
+                    (_, 0) => '>',
+                    _ => match rng.below(10) {
+                        0..=2 => '>',
+                        3..=5 => 'v',
+                        _ => '.',
+                    },
+                })
+                .collect()
+        })
+        .collect()
+}
+
+const EXAMPLES: &[Example] = &[Example {
+    name: "example",
+    input: "\
+v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>",
+    part1: Some("58"),
+    part2: None,
+}];
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const EXAMPLES: &'static [Example] = EXAMPLES;
+    const GENERATOR: Option<Generator> = Some(generate);
+    const DUMP: Option<fn(&SeaFloor) -> Pnm> = Some(dump);
+
+    type Input = SeaFloor;
+    type Answer1 = Outcome<usize, Cycled>;
+    type Answer2 = &'static str;
+
+    // there is no second puzzle on the last day
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(lines: &[String]) -> Result<SeaFloor, ParseError> {
+        let sea_floor = Grid::parse(
+            Self::DAY,
+            lines,
+            |c| match c {
+                '.' => Some(None),
+                c => CucumberType::try_from(c).ok().map(Some),
+            },
+            "expected >, v or .",
+        )?;
+        Ok(sea_floor.wrapping())
+    }
+
+    fn part1(sea_floor: &SeaFloor) -> Outcome<usize, Cycled> {
+        steps_until_stuck(sea_floor).into()
+    }
+
+    fn part2(_: &SeaFloor) -> &'static str {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::check_examples;
+
+    #[test]
+    fn it_works() {
+        check_examples::<Day25>();
+
+        // step 0 and an east and a south frame for every one of the 58 steps
+        let sea_floor = Day25::parse(&EXAMPLES[0].lines()).unwrap();
+        let (steps, frames) = crate::visualize::recording(|| steps_until_stuck(&sea_floor));
+        assert_eq!((steps, frames.len()), (Ok(58), 1 + 2 * 58));
+        assert_eq!(frames.last().unwrap().title, "step 58 south");
+
+        let image = dump(&sea_floor);
+        assert!(image
+            .to_string()
+            .starts_with("P2\n10 9\n2\n2 2 0 0 1 0 1 1 2 2\n"));
+        match image {
+            Pnm::Graymap { grid, .. } => {
+                assert_eq!((grid[(0, 4)], grid[(7, 4)], grid[(9, 8)]), (1, 0, 2));
+            }
+            other => panic!("expected a graymap, got {:?}", other),
+        }
+
+        // the one cucumber wraps around the 1x2 ring and is back after 2 steps
+        let ring = Day25::parse(&[">.".to_string()]).unwrap();
+        assert_eq!(
+            Day25::part1(&ring).to_string(),
+            "no answer: the state at step 0 comes back every 2 steps"
+        );
+        assert_eq!(
+            stuck_map(&ring),
+            Err(Cycled {
+                start: 0,
+                period: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Part;
+#[cfg(test)]
+use crate::Solution;
+
+// example input from the puzzle text together with the answers given there
+#[derive(Debug)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn lines(&self) -> Vec<String> {
+        self.input.lines().map(|line| line.to_string()).collect()
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+// every day's tests run through its registered examples
+#[cfg(test)]
+pub(crate) fn check_examples<S: Solution>() {
+    assert!(!S::EXAMPLES.is_empty(), "day {} has no examples", S::DAY);
+
+    for example in S::EXAMPLES.iter() {
+        let input = S::parse(&example.lines())
+            .unwrap_or_else(|e| panic!("example {}: {}", example.name, e));
+        if let Some(expected) = example.part1 {
+            assert_eq!(
+                S::part1(&input).to_string(),
+                expected,
+                "day {} example {} part 1",
+                S::DAY,
+                example.name
+            );
+        }
+        if let Some(expected) = example.part2 {
+            assert_eq!(
+                S::part2(&input).to_string(),
+                expected,
+                "day {} example {} part 2",
+                S::DAY,
+                example.name
+            );
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::RangeInclusive;
+
+// makes a day's input, size is the day specific knob e.g. number of lines or side of a map
+pub type Generator = fn(&mut Rng, usize) -> Vec<String>;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+// splitmix64, small and good enough to make reproducible inputs from a seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // uniform in 0..n, n must not be 0
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = (range.end() - range.start()) as u64 + 1;
+        range.start() + (self.next_u64() % span) as i64
+    }
+
+    // true with a chance of percent in 100
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DAYS;
+
+    #[test]
+    fn it_works() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let values = (0..10).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(values, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(values[0], Rng::new(8).next_u64());
+        for _ in 0..100 {
+            assert!(a.below(3) < 3);
+            assert!((-2..=2).contains(&a.range(-2..=2)));
+        }
+
+        // every generator makes input its own day parses and solves
+        for day in DAYS.iter().filter(|day| day.runner.generator().is_some()) {
+            let lines = day.generate(10, 1).unwrap();
+            assert_eq!(lines, day.generate(10, 1).unwrap(), "day {}", day.day);
+            let answers = day
+                .run(&lines, day.runner.parts())
+                .unwrap_or_else(|e| panic!("day {}: {}", day.day, e));
+            assert_eq!(answers.len(), day.runner.parts().len());
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use crate::parse::{input_line, input_lines, Line};
+use crate::ParseError;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+// (x, y), signed so neighbours of the border can be expressed before they are checked
+pub type Pos = (i32, i32);
+
+const NEIGHBOURS4: [Pos; 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS8: [Pos; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// rectangular map stored row by row
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    cols: usize,
+    lines: usize,
+    cells: Vec<T>,
+    // positions outside wrap around to the other side like a torus
+    wrap: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn new(cols: usize, lines: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cols,
+            lines,
+            cells: vec![value; cols * lines],
+            wrap: false,
+        }
+    }
+
+    pub fn from_fn(cols: usize, lines: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let mut cells = Vec::with_capacity(cols * lines);
+        for y in 0..lines {
+            for x in 0..cols {
+                cells.push(f((x as i32, y as i32)));
+            }
+        }
+        Grid {
+            cols,
+            lines,
+            cells,
+            wrap: false,
+        }
+    }
+
+    // every char of every line goes through cell, None is reported with message
+    pub fn parse(
+        day: u8,
+        lines: &[String],
+        cell: impl Fn(char) -> Option<T>,
+        message: &str,
+    ) -> Result<Self, ParseError> {
+        // an empty map is an error
+        input_line(day, lines, 0)?;
+        Grid::from_lines(input_lines(day, lines), cell, message)
+    }
+
+    // for maps that are only one section of the input, the first line sets the width
+    pub fn from_lines<'a>(
+        lines: impl Iterator<Item = Line<'a>>,
+        cell: impl Fn(char) -> Option<T>,
+        message: &str,
+    ) -> Result<Self, ParseError> {
+        let mut lines = lines.peekable();
+        let cols = lines.peek().map_or(0, |line| line.text.chars().count());
+        let mut cells = vec![];
+        let mut rows = 0;
+        for line in lines {
+            rows += 1;
+            let mut count = 0;
+            for (i, c) in line.text.char_indices() {
+                let token = &line.text[i..i + c.len_utf8()];
+                if count == cols {
+                    return Err(line.error(token, format!("expected {} columns", cols)));
+                }
+                cells.push(cell(c).ok_or_else(|| line.error(token, message))?);
+                count += 1;
+            }
+            if count != cols {
+                return Err(line.error(line.end(), format!("expected {} columns", cols)));
+            }
+        }
+        Ok(Grid {
+            cols,
+            lines: rows,
+            cells,
+            wrap: false,
+        })
+    }
+
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // the position inside the grid for pos, None if it is outside and the grid doesn't wrap
+    pub fn resolve(&self, pos: Pos) -> Option<Pos> {
+        if self.is_empty() {
+            return None;
+        }
+        if self.wrap {
+            return Some((
+                pos.0.rem_euclid(self.cols as i32),
+                pos.1.rem_euclid(self.lines as i32),
+            ));
+        }
+        if pos.0 >= 0 && pos.1 >= 0 && (pos.0 as usize) < self.cols && (pos.1 as usize) < self.lines
+        {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        self.resolve(pos)
+            .map(|(x, y)| y as usize * self.cols + x as usize)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(move |i| &mut self.cells[i])
+    }
+
+    pub fn step(&self, pos: Pos, direction: Pos) -> Option<Pos> {
+        self.resolve((pos.0 + direction.0, pos.1 + direction.1))
+    }
+
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4.iter().filter_map(move |d| self.step(pos, *d))
+    }
+
+    // including the diagonals
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8.iter().filter_map(move |d| self.step(pos, *d))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.cells.len()).map(move |i| ((i % cols) as i32, (i / cols) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cols: self.cols,
+            lines: self.lines,
+            cells: self.cells.iter().map(f).collect(),
+            wrap: self.wrap,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+// one line per row, cells are expected to render as a single char
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.cols.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let lines = ["123", "456"].map(|s| s.to_string());
+        let grid = Grid::parse(1, &lines, |c| c.to_digit(10), "not a digit").unwrap();
+        assert_eq!((grid.cols(), grid.lines(), grid.len()), (3, 2, 6));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let n4 = grid.neighbours4((0, 0)).collect::<Vec<_>>();
+        assert_eq!(n4, vec![(1, 0), (0, 1)]);
+        let n8 = grid.neighbours8((1, 0)).collect::<Vec<_>>();
+        assert_eq!(n8, vec![(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+
+        let torus = grid.clone().wrapping();
+        assert_eq!(torus.get((3, 0)), Some(&1));
+        assert_eq!(torus.get((-1, -1)), Some(&6));
+        assert_eq!(torus.neighbours4((0, 0)).count(), 4);
+        assert_eq!(torus.step((2, 1), (1, 0)), Some((0, 1)));
+
+        let doubled = grid.map(|v| v * 2);
+        assert_eq!(doubled.values().sum::<u32>(), 42);
+        let squares = Grid::from_fn(2, 2, |(x, y)| x * 2 + y);
+        assert_eq!(squares.iter().last(), Some(((1, 1), &3)));
+
+        let lines = ["123", "4x6"].map(|s| s.to_string());
+        let err = Grid::parse(1, &lines, |c| c.to_digit(10), "not a digit").unwrap_err();
+        assert_eq!(err.to_string(), "day 1 line 2 column 2: not a digit `x'");
+        let lines = ["123", "45"].map(|s| s.to_string());
+        let err = Grid::parse(1, &lines, |c| c.to_digit(10), "not a digit").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1 line 2 column 3: expected 3 columns `'"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// where to read the puzzle input from
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    // search the default locations for input_day<N>.txt
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for InputSource {
+    fn from(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    // used to tell real input and other files apart e.g. "input_day1" or "stdin"
+    pub name: String,
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("no input found for day {day}, tried {}", Paths(.tried))]
+    NotFound { day: u8, tried: Vec<PathBuf> },
+    #[error("failed to read {}: {source}", .path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to read stdin: {0}")]
+    Stdin(io::Error),
+}
+
+struct Paths<'a>(&'a [PathBuf]);
+
+impl Display for Paths<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, path) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+pub fn input_file_name(day: u8) -> String {
+    format!("input_day{}.txt", day)
+}
+
+// $AOC_INPUT_DIR first, then input/ in the working directory and in the crate root
+pub fn candidate_paths(day: u8) -> Vec<PathBuf> {
+    let file_name = input_file_name(day);
+    let mut paths = vec![];
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        paths.push(Path::new(&dir).join(&file_name));
+    }
+    paths.push(Path::new("input").join(&file_name));
+    let crate_input = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("input")
+        .join(&file_name);
+    if !paths.contains(&crate_input) {
+        paths.push(crate_input);
+    }
+    paths
+}
+
+pub fn read_input(day: u8, source: &InputSource) -> Result<Input, InputError> {
+    match source {
+        InputSource::Stdin => {
+            let lines = io::stdin()
+                .lock()
+                .lines()
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(InputError::Stdin)?;
+            Ok(Input {
+                name: "stdin".to_string(),
+                lines,
+            })
+        }
+        InputSource::Path(path) => {
+            if !path.is_file() {
+                return Err(InputError::NotFound {
+                    day,
+                    tried: vec![path.clone()],
+                });
+            }
+            read_input_file(path)
+        }
+        InputSource::Default => {
+            let tried = candidate_paths(day);
+            match tried.iter().find(|path| path.is_file()) {
+                Some(path) => read_input_file(path),
+                None => Err(InputError::NotFound { day, tried }),
+            }
+        }
+    }
+}
+
+fn read_input_file(path: &Path) -> Result<Input, InputError> {
+    let read_error = |source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    };
+    let file = std::fs::File::open(path).map_err(read_error)?;
+    let lines = io::BufReader::new(file)
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(read_error)?;
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string());
+    Ok(Input { name, lines })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(InputSource::from("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from("input_test/input_test_day22.txt"),
+            InputSource::Path(PathBuf::from("input_test/input_test_day22.txt"))
+        );
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("input_test/input_test_day22.txt");
+        let input = read_input(22, &InputSource::Path(path)).unwrap();
+        assert_eq!(input.name, "input_test_day22");
+        assert_eq!(input.lines.len(), 60);
+
+        let missing = PathBuf::from("does/not/exist.txt");
+        let err = read_input(1, &InputSource::Path(missing)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no input found for day 1, tried does/not/exist.txt"
+        );
+    }
+}
@@ -1,6 +1,61 @@
+use std::any::Any;
+use std::fmt::Display;
 use std::fs::File;
 use std::io;
 use std::io::{BufRead, Read};
+use std::marker::PhantomData;
+
+pub use examples::Example;
+use generate::{Generator, Rng};
+pub use grid::Grid;
+use parse::Check;
+pub use parse::ParseError;
+use pnm::Pnm;
+use repl::{Repl, ReplFactory};
+
+pub mod answers;
+pub mod bench;
+pub mod checked;
+pub mod examples;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod ocr;
+pub mod parse;
+pub mod pnm;
+#[cfg(test)]
+mod property;
+pub mod repl;
+pub mod report;
+pub mod shortest_path;
+pub mod simulate;
+pub mod visualize;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
 
 pub fn read_lines(filepath: &str) -> io::Result<io::Lines<io::BufReader<File>>> {
     let file = File::open(filepath)?;
@@ -9,7 +64,7 @@ pub fn read_lines(filepath: &str) -> io::Result<io::Lines<io::BufReader<File>>>
 
 pub fn read_lines_as_vec(filepath: &str) -> io::Result<Vec<String>> {
     let lines = read_lines(filepath)?;
-    Ok(lines.flatten().collect())
+    Ok(lines.map_while(Result::ok).collect())
 }
 
 pub fn read_lines_as_string(filepath: &str) -> io::Result<String> {
@@ -18,3 +73,231 @@ pub fn read_lines_as_string(filepath: &str) -> io::Result<String> {
     file.read_to_string(&mut lines)?;
     Ok(lines)
 }
+
+// every day parses its input once and solves both parts from that
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    const PARTS: &'static [Part] = &Part::ALL;
+    const EXAMPLES: &'static [Example] = &[];
+    const GENERATOR: Option<Generator> = None;
+    // the final state as an image, for days where that is worth looking at
+    const DUMP: Option<fn(&Self::Input) -> Pnm> = None;
+    // a session that evaluates single lines, for days whose input is a little language
+    const REPL: Option<ReplFactory> = None;
+    // every problem of the input instead of the first one parse stops at
+    const CHECK: Option<Check> = None;
+
+    fn parse(lines: &[String]) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+// what the answer is replaced with when there is none, the report tells these apart by it
+pub const NO_ANSWER: &str = "no answer: ";
+
+// an answer or why the input has none, for parts that can fail on valid input, the error is
+// printed in place of the answer instead of panicking
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Outcome<T, E>(pub Result<T, E>);
+
+impl<T, E> From<Result<T, E>> for Outcome<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        Outcome(result)
+    }
+}
+
+impl<T: Display, E: Display> Display for Outcome<T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Ok(answer) => write!(f, "{}", answer),
+            Err(e) => write!(f, "{}{}", NO_ANSWER, e),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<&str> for Part {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+// type erased view of a Solution so all days can live in one table
+pub trait Runner: Sync {
+    fn parts(&self) -> &'static [Part];
+    fn examples(&self) -> &'static [Example];
+    fn generator(&self) -> Option<Generator>;
+    fn parse(&self, lines: &[String]) -> Result<Box<dyn Any + Send>, ParseError>;
+    fn solve(&self, input: &(dyn Any + Send), part: Part) -> String;
+    fn dump(&self, input: &(dyn Any + Send)) -> Option<Pnm>;
+    fn repl(&self) -> Option<ReplFactory>;
+    fn check(&self, lines: &[String]) -> Vec<ParseError>;
+}
+
+struct SolutionRunner<S>(PhantomData<fn() -> S>);
+
+impl<S> Runner for SolutionRunner<S>
+where
+    S: Solution,
+    S::Input: Send + 'static,
+{
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn generator(&self) -> Option<Generator> {
+        S::GENERATOR
+    }
+
+    fn parse(&self, lines: &[String]) -> Result<Box<dyn Any + Send>, ParseError> {
+        let input = S::parse(lines)?;
+        Ok(Box::new(input))
+    }
+
+    fn solve(&self, input: &(dyn Any + Send), part: Part) -> String {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different day");
+        match part {
+            Part::One => S::part1(input).to_string(),
+            Part::Two => S::part2(input).to_string(),
+        }
+    }
+
+    fn dump(&self, input: &(dyn Any + Send)) -> Option<Pnm> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different day");
+        S::DUMP.map(|dump| dump(input))
+    }
+
+    fn repl(&self) -> Option<ReplFactory> {
+        S::REPL
+    }
+
+    // days without a check still report the error of parse, so does a check that missed it
+    fn check(&self, lines: &[String]) -> Vec<ParseError> {
+        match S::CHECK.map(|check| check(lines)) {
+            Some(problems) if !problems.is_empty() => problems,
+            _ => S::parse(lines).err().into_iter().collect(),
+        }
+    }
+}
+
+pub struct Day {
+    pub day: u8,
+    pub runner: &'static dyn Runner,
+}
+
+macro_rules! days {
+    ($($solution:ty),* $(,)?) => {
+        pub static DAYS: &[Day] = &[
+            $(Day { day: <$solution as Solution>::DAY, runner: &SolutionRunner::<$solution>(PhantomData) },)*
+        ];
+    };
+}
+
+days! {
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    day13::Day13,
+    day14::Day14,
+    day15::Day15,
+    day16::Day16,
+    day17::Day17,
+    day18::Day18,
+    day20::Day20,
+    day21::Day21,
+    day22::Day22,
+    day23::Day23,
+    day24::Day24,
+    day25::Day25,
+}
+
+pub fn get_day(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+impl Day {
+    // the first example unless a name is given
+    pub fn example(&self, name: Option<&str>) -> Option<&'static Example> {
+        let examples = self.runner.examples();
+        match name {
+            Some(name) => examples.iter().find(|example| example.name == name),
+            None => examples.first(),
+        }
+    }
+
+    // a random input of the given size, the same for the same seed
+    pub fn generate(&self, size: usize, seed: u64) -> Option<Vec<String>> {
+        let generator = self.runner.generator()?;
+        Some(generator(&mut Rng::new(seed), size))
+    }
+
+    // None if the day has nothing to dump
+    pub fn dump(&self, lines: &[String]) -> Result<Option<Pnm>, ParseError> {
+        let input = self.runner.parse(lines)?;
+        Ok(self.runner.dump(input.as_ref()))
+    }
+
+    // every problem found in the input, empty if it parses
+    pub fn check(&self, lines: &[String]) -> Vec<ParseError> {
+        self.runner.check(lines)
+    }
+
+    // a fresh session, None if the day has no repl
+    pub fn repl(&self) -> Option<Box<dyn Repl>> {
+        self.runner.repl().map(|repl| repl())
+    }
+
+    pub fn run(&self, lines: &[String], parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError> {
+        let input = self.runner.parse(lines)?;
+        Ok(parts
+            .iter()
+            .map(|part| (*part, self.runner.solve(input.as_ref(), *part)))
+            .collect())
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt::{Arguments, Display, Formatter};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 6] = [
+        Level::Off,
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    // -v is info, -vv debug and -vvv or more trace
+    pub fn from_verbosity(count: usize) -> Level {
+        Level::ALL[(Level::Warn as usize + count).min(Level::Trace as usize)]
+    }
+}
+
+impl TryFrom<&str> for Level {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, ()> {
+        Level::ALL
+            .iter()
+            .find(|level| level.to_string() == s)
+            .copied()
+            .ok_or(())
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// a level for everything and overrides for single days
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Filter {
+    pub level: Level,
+    pub days: Vec<(u8, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter {
+            level: Level::Warn,
+            days: vec![],
+        }
+    }
+}
+
+impl Filter {
+    // like AOC_LOG, comma separated levels where day<n>=<level> only applies to that day
+    // e.g. `info,day23=trace'
+    pub fn parse(spec: &str) -> Result<Filter, String> {
+        let mut filter = Filter::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let invalid = || format!("invalid log directive `{}'", directive);
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    let day = target
+                        .strip_prefix("day")
+                        .and_then(|day| day.parse::<u8>().ok())
+                        .ok_or_else(invalid)?;
+                    let level = Level::try_from(level).map_err(|_| invalid())?;
+                    filter.days.push((day, level));
+                }
+                None => filter.level = Level::try_from(directive).map_err(|_| invalid())?,
+            }
+        }
+        Ok(filter)
+    }
+
+    fn level_for(&self, day: Option<u8>) -> Level {
+        self.days
+            .iter()
+            .rev()
+            .find(|(d, _)| Some(*d) == day)
+            .map_or(self.level, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> Level {
+        self.days
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.level, Level::max)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter {
+    level: Level::Warn,
+    days: vec![],
+});
+// nothing above it is logged anywhere, keeps disabled calls in hot loops cheap
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_filter(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    *FILTER.write().unwrap() = filter;
+}
+
+// the day of a module path like aoc_2021::day23, None for the shared modules
+fn target_day(module: &str) -> Option<u8> {
+    module
+        .rsplit("::")
+        .next()?
+        .strip_prefix("day")?
+        .parse()
+        .ok()
+}
+
+pub fn enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    level <= FILTER.read().unwrap().level_for(target_day(module))
+}
+
+// only called by the macros once enabled said yes
+pub fn write(level: Level, module: &str, args: Arguments) {
+    let target = module.rsplit("::").next().unwrap_or(module);
+    eprintln!("[{} {}] {}", level, target, args);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::log::enabled($crate::log::Level::$level, module_path!()) {
+            $crate::log::write($crate::log::Level::$level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!(Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!(Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!(Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!(Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!(Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(9), Level::Trace);
+
+        let filter = Filter::parse("info, day23=trace,day5=off").unwrap();
+        assert_eq!(filter.level_for(None), Level::Info);
+        assert_eq!(filter.level_for(Some(23)), Level::Trace);
+        assert_eq!(filter.level_for(Some(5)), Level::Off);
+        assert_eq!(filter.max_level(), Level::Trace);
+        assert_eq!(Filter::parse("").unwrap(), Filter::default());
+        assert_eq!(
+            Filter::parse("day=info").unwrap_err(),
+            "invalid log directive `day=info'"
+        );
+        assert!(Filter::parse("loud").is_err());
+
+        assert_eq!(target_day("aoc_2021::day05"), Some(5));
+        assert_eq!(target_day("aoc_2021::grid"), None);
+
+        // the default filter, tests run in parallel so it is never changed here
+        assert!(enabled(Level::Warn, "aoc_2021::day01"));
+        assert!(!enabled(Level::Trace, "aoc_2021::day01"));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::Grid;
+use thiserror::Error;
+
+const HEIGHT: usize = 6;
+// 4 columns of most letters and an empty one between letters
+const ADVANCE: usize = 5;
+
+// the capital letters the puzzles draw with dots, all are 6 high and 4 wide except Y
+const FONT: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+// block is the part of the dots that couldn't be read, rendered with # and .
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum OcrError {
+    #[error("expected letters {HEIGHT} dots high, got {lines}:\n{block}")]
+    Height { lines: usize, block: String },
+    #[error("unknown glyph at column {column}:\n{block}")]
+    UnknownGlyph { column: usize, block: String },
+}
+
+// rows joined by newlines like the FONT, columns outside the dots are left out
+fn render(dots: &Grid<bool>, columns: std::ops::Range<usize>, lines: usize) -> String {
+    (0..lines)
+        .map(|y| {
+            columns
+                .clone()
+                .filter(|x| *x < dots.cols())
+                .map(|x| if dots[(x as i32, y as i32)] { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// reads the letters from left to right, empty rows and columns at the end are ignored
+pub fn read_letters(dots: &Grid<bool>) -> Result<String, OcrError> {
+    let used = |x: usize, y: usize| dots[(x as i32, y as i32)];
+    let cols = (0..dots.cols())
+        .rev()
+        .find(|x| (0..dots.lines()).any(|y| used(*x, y)))
+        .map_or(0, |x| x + 1);
+    let lines = (0..dots.lines())
+        .rev()
+        .find(|y| (0..dots.cols()).any(|x| used(x, *y)))
+        .map_or(0, |y| y + 1);
+    if lines != HEIGHT {
+        return Err(OcrError::Height {
+            lines,
+            block: render(dots, 0..dots.cols(), dots.lines()),
+        });
+    }
+
+    let mut letters = String::new();
+    let mut column = 0;
+    while column < cols {
+        // the letter that fits with an empty column right after it
+        let (letter, width) = FONT
+            .iter()
+            .find_map(|(letter, shape)| {
+                let width = shape.find('\n').unwrap_or(shape.len());
+                let spacer = column + width;
+                let spaced = spacer >= dots.cols() || (0..HEIGHT).all(|y| !used(spacer, y));
+                (spaced && render(dots, column..spacer, HEIGHT) == *shape)
+                    .then_some((*letter, width))
+            })
+            .ok_or_else(|| OcrError::UnknownGlyph {
+                column,
+                block: render(dots, column..column + ADVANCE, HEIGHT),
+            })?;
+        letters.push(letter);
+        column += width + 1;
+    }
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dots(rows: &[&str]) -> Grid<bool> {
+        let lines = rows.iter().map(|row| row.to_string()).collect::<Vec<_>>();
+        Grid::parse(13, &lines, |c| Some(c == '#'), "").unwrap()
+    }
+
+    #[test]
+    fn it_works() {
+        let hi = dots(&[
+            "#..#.####.......",
+            "#..#.#..........",
+            "####.###........",
+            "#..#.#..........",
+            "#..#.#..........",
+            "#..#.#..........",
+            "................",
+        ]);
+        assert_eq!(read_letters(&hi), Ok("HF".to_string()));
+
+        let i = dots(&[
+            ".###.####",
+            "..#..#...",
+            "..#..###.",
+            "..#..#...",
+            "..#..#...",
+            ".###.####",
+        ]);
+        assert_eq!(read_letters(&i), Ok("IE".to_string()));
+
+        // Y is a column wider than the others, the next letter starts after its spacer
+        let y = dots(&[
+            "#...#..##.",
+            "#...#.#..#",
+            ".#.#..#..#",
+            "..#...####",
+            "..#...#..#",
+            "..#...#..#",
+        ]);
+        assert_eq!(read_letters(&y), Ok("YA".to_string()));
+
+        let unknown = dots(&[
+            "#..#.#...#",
+            "#..#.##.##",
+            "####.#.#.#",
+            "#..#.#...#",
+            "#..#.#...#",
+            "#..#.#...#",
+        ]);
+        assert_eq!(
+            read_letters(&unknown).unwrap_err().to_string(),
+            "unknown glyph at column 5:\n#...#\n##.##\n#.#.#\n#...#\n#...#\n#...#"
+        );
+
+        let low = dots(&["##", "##"]);
+        assert_eq!(
+            read_letters(&low),
+            Err(OcrError::Height {
+                lines: 2,
+                block: "##\n##".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,410 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+#[error("day {day} {}line {line} column {column}: {message} `{text}'", SectionPrefix(*.section))]
+pub struct ParseError {
+    pub day: u8,
+    // section, line and column are 1 based like in an editor
+    pub section: Option<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+struct SectionPrefix(Option<usize>);
+
+impl Display for SectionPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(section) => write!(f, "section {} ", section),
+            None => Ok(()),
+        }
+    }
+}
+
+// every problem of an input, for aoc check
+pub type Check = fn(&[String]) -> Vec<ParseError>;
+
+// every error of an input instead of only the first, parsers that keep going after an error
+// push to it and give up on the value at the end
+#[derive(Debug, Default)]
+pub struct Problems {
+    errors: Vec<ParseError>,
+}
+
+impl Problems {
+    pub fn push(&mut self, error: ParseError) {
+        self.errors.push(error);
+    }
+
+    // the value, or None and the error is kept
+    pub fn take<T>(&mut self, result: Result<T, ParseError>) -> Option<T> {
+        result.map_err(|e| self.push(e)).ok()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    // a normal parse, the error is the first problem in the input, a reader that gave up
+    // without saying why still fails
+    pub fn first<T>(
+        day: u8,
+        read: impl FnOnce(&mut Problems) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        let mut problems = Problems::default();
+        let value = read(&mut problems);
+        match (problems.sorted().into_iter().next(), value) {
+            (Some(error), _) => Err(error),
+            (None, Some(value)) => Ok(value),
+            (None, None) => Err(ParseError {
+                day,
+                section: None,
+                line: 1,
+                column: 1,
+                text: "".to_string(),
+                message: "input could not be read".to_string(),
+            }),
+        }
+    }
+
+    // all problems in input order, for aoc check
+    pub fn all<T>(read: impl FnOnce(&mut Problems) -> Option<T>) -> Vec<ParseError> {
+        let mut problems = Problems::default();
+        read(&mut problems);
+        problems.sorted()
+    }
+
+    fn sorted(mut self) -> Vec<ParseError> {
+        self.errors.sort_by_key(|e| (e.line, e.column));
+        self.errors
+    }
+}
+
+// a line of puzzle input that knows where it came from so errors can point at it
+#[derive(Debug, Copy, Clone)]
+pub struct Line<'a> {
+    pub day: u8,
+    // set when the line was split off into a section
+    pub section: Option<usize>,
+    pub index: usize,
+    pub text: &'a str,
+}
+
+pub fn input_lines(day: u8, lines: &[String]) -> impl Iterator<Item = Line<'_>> {
+    lines.iter().enumerate().map(move |(index, text)| Line {
+        day,
+        section: None,
+        index,
+        text: text.as_str(),
+    })
+}
+
+pub fn input_line(day: u8, lines: &[String], index: usize) -> Result<Line<'_>, ParseError> {
+    match lines.get(index) {
+        Some(text) => Ok(Line {
+            day,
+            section: None,
+            index,
+            text: text.as_str(),
+        }),
+        None => Err(ParseError {
+            day,
+            section: None,
+            line: index + 1,
+            column: 1,
+            text: "".to_string(),
+            message: "unexpected end of input".to_string(),
+        }),
+    }
+}
+
+impl<'a> Line<'a> {
+    pub fn number(&self) -> usize {
+        self.index + 1
+    }
+
+    // token must be a slice of text, anything else points at the start of the line
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let pos = token.as_ptr() as usize;
+        if pos < start || pos > start + self.text.len() {
+            return 1;
+        }
+        self.text[..pos - start].chars().count() + 1
+    }
+
+    // empty slice at the end of the line for "missing ..." errors
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+
+    pub fn error(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: self.day,
+            section: self.section,
+            line: self.number(),
+            column: self.column_of(token),
+            text: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token
+            .parse()
+            .map_err(|e: T::Err| self.error(token, e.to_string()))
+    }
+
+    pub fn parse_all<T>(&self, tokens: impl Iterator<Item = &'a str>) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        tokens.map(|token| self.parse(token)).collect()
+    }
+
+    pub fn split_once(&self, pattern: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(pattern)
+            .ok_or_else(|| self.error(self.text, format!("missing `{}' in", pattern)))
+    }
+
+    // the whole line as one record
+    pub fn record<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse(self.text)
+    }
+
+    // every char as a decimal digit, the usual height/energy/risk maps
+    pub fn digits(&self) -> Result<Vec<u32>, ParseError> {
+        self.text
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| self.error(&self.text[i..i + c.len_utf8()], "not a digit"))
+            })
+            .collect()
+    }
+}
+
+// consecutive lines of input between blank lines, never empty
+#[derive(Debug, Clone)]
+pub struct Section<'a> {
+    pub day: u8,
+    pub index: usize,
+    pub lines: Vec<Line<'a>>,
+}
+
+impl<'a> Section<'a> {
+    pub fn number(&self) -> usize {
+        self.index + 1
+    }
+
+    pub fn first(&self) -> Line<'a> {
+        self.lines[0]
+    }
+
+    // for sections like a header that must be exactly one line
+    pub fn single(&self) -> Result<Line<'a>, ParseError> {
+        match self.lines.get(1) {
+            Some(line) => Err(line.error(line.text, "expected a single line, got another")),
+            None => Ok(self.first()),
+        }
+    }
+
+    pub fn records<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.lines.iter().map(|line| line.record()).collect()
+    }
+}
+
+// the input split on blank lines
+#[derive(Debug, Clone)]
+pub struct Sections<'a> {
+    day: u8,
+    line_count: usize,
+    sections: Vec<Section<'a>>,
+}
+
+// runs of blank lines count as one separator, leading and trailing ones are ignored
+pub fn sections(day: u8, lines: &[String]) -> Sections<'_> {
+    let mut sections: Vec<Section> = vec![];
+    let mut in_section = false;
+    for mut line in input_lines(day, lines) {
+        if line.text.trim().is_empty() {
+            in_section = false;
+            continue;
+        }
+        if !in_section {
+            sections.push(Section {
+                day,
+                index: sections.len(),
+                lines: vec![],
+            });
+            in_section = true;
+        }
+        let section = sections.last_mut().unwrap();
+        line.section = Some(section.number());
+        section.lines.push(line);
+    }
+    Sections {
+        day,
+        line_count: lines.len(),
+        sections,
+    }
+}
+
+impl<'a> Sections<'a> {
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Result<&Section<'a>, ParseError> {
+        self.sections.get(index).ok_or_else(|| ParseError {
+            day: self.day,
+            section: Some(index + 1),
+            line: self.line_count + 1,
+            column: 1,
+            text: "".to_string(),
+            message: "missing section".to_string(),
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Section<'a>> {
+        self.sections.iter()
+    }
+
+    // errors on a missing section or any after count
+    pub fn expect(&self, count: usize) -> Result<(), ParseError> {
+        if count > 0 {
+            self.get(count - 1)?;
+        }
+        match self.sections.get(count) {
+            Some(extra) => {
+                let line = extra.first();
+                Err(line.error(line.text, format!("expected {} sections, got more", count)))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let lines = ["0,9 -> 5,9", "8,x -> 0,8"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+
+        let line = input_line(5, &lines, 0).unwrap();
+        let (start, end) = line.split_once(" -> ").unwrap();
+        assert_eq!(line.column_of(end), 8);
+        let values = line.parse_all::<u16>(start.split(',')).unwrap();
+        assert_eq!(values, vec![0, 9]);
+
+        let line = input_line(5, &lines, 1).unwrap();
+        let (start, _) = line.split_once(" -> ").unwrap();
+        let err = line.parse_all::<u16>(start.split(',')).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+        assert_eq!(
+            err.to_string(),
+            "day 5 line 2 column 3: invalid digit found in string `x'"
+        );
+
+        let err = line.split_once(" => ").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 5 line 2 column 1: missing ` => ' in `8,x -> 0,8'"
+        );
+        let err = line.digits().unwrap_err();
+        assert_eq!(err.column, 2);
+
+        let err = input_line(5, &lines, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 5 line 3 column 1: unexpected end of input `'"
+        );
+
+        let lines = ["1,2", "", "", "3", "x", "", "5", ""]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let sections = sections(4, &lines);
+        assert_eq!(sections.len(), 3);
+        let header = sections.get(0).unwrap().single().unwrap();
+        assert_eq!(
+            header.parse_all::<u8>(header.text.split(',')),
+            Ok(vec![1, 2])
+        );
+        let err = sections.get(1).unwrap().records::<u8>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 4 section 2 line 5 column 1: invalid digit found in string `x'"
+        );
+        assert!(sections.get(1).unwrap().single().is_err());
+        assert_eq!(sections.get(2).unwrap().records::<u8>(), Ok(vec![5]));
+        assert!(sections.expect(3).is_ok());
+        let err = sections.expect(2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 4 section 3 line 7 column 1: expected 2 sections, got more `5'"
+        );
+        let err = sections.expect(4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 4 section 4 line 9 column 1: missing section `'"
+        );
+
+        let lines = ["1 x", "y 2"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+        let read = |problems: &mut Problems| {
+            let mut numbers = vec![];
+            let lines = input_lines(1, &lines).collect::<Vec<_>>();
+            for line in lines.iter().rev() {
+                for token in line.text.split(' ') {
+                    numbers.extend(problems.take(line.parse::<u8>(token)));
+                }
+            }
+            problems.is_empty().then_some(numbers)
+        };
+        let all = Problems::all(read);
+        assert_eq!(
+            all.iter().map(|e| (e.line, e.column)).collect::<Vec<_>>(),
+            vec![(1, 3), (2, 1)]
+        );
+        assert_eq!(Problems::first(1, read), Err(all[0].clone()));
+        let err = Problems::first(1, |_| None::<u8>).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1 line 1 column 1: input could not be read `'"
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::Grid;
+use std::fmt::{Display, Formatter};
+
+pub type Rgb = (u8, u8, u8);
+
+pub const WHITE: Rgb = (255, 255, 255);
+pub const BLACK: Rgb = (0, 0, 0);
+
+// portable anymap in the plain text formats so dumps can be diffed in reviews
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Pnm {
+    // P1, true is black
+    Bitmap(Grid<bool>),
+    // P2, 0 is black and max white
+    Graymap { grid: Grid<u16>, max: u16 },
+    // P3
+    Pixmap(Grid<Rgb>),
+}
+
+impl Pnm {
+    // the brightest value of the grid is white
+    pub fn graymap(grid: Grid<u16>) -> Self {
+        let max = grid.values().copied().max().unwrap_or(0).max(1);
+        Pnm::Graymap { grid, max }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Pnm::Bitmap(_) => "pbm",
+            Pnm::Graymap { .. } => "pgm",
+            Pnm::Pixmap(_) => "ppm",
+        }
+    }
+}
+
+// a colour for every label that is never too dark to tell apart from black
+pub fn label_colour(label: usize) -> Rgb {
+    let hash = (label as u64 + 1).wrapping_mul(0x9e3779b97f4a7c15);
+    let channel = |shift: u32| (hash >> shift) as u8 | 0x40;
+    (channel(16), channel(32), channel(48))
+}
+
+// every row starts on a new line, wrapped so no line is longer than 70 chars
+fn write_rows<T>(
+    f: &mut Formatter<'_>,
+    grid: &Grid<T>,
+    token: impl Fn(&T) -> String,
+) -> std::fmt::Result {
+    let values = grid.values().collect::<Vec<_>>();
+    for row in values.chunks(grid.cols().max(1)) {
+        let mut length = 0;
+        for value in row {
+            let token = token(value);
+            if length > 0 && length + 1 + token.len() > 70 {
+                writeln!(f)?;
+                length = 0;
+            }
+            if length > 0 {
+                write!(f, " ")?;
+                length += 1;
+            }
+            write!(f, "{}", token)?;
+            length += token.len();
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+impl Display for Pnm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pnm::Bitmap(grid) => {
+                writeln!(f, "P1\n{} {}", grid.cols(), grid.lines())?;
+                write_rows(f, grid, |black| if *black { "1" } else { "0" }.to_string())
+            }
+            Pnm::Graymap { grid, max } => {
+                writeln!(f, "P2\n{} {}\n{}", grid.cols(), grid.lines(), max)?;
+                write_rows(f, grid, |value| value.to_string())
+            }
+            Pnm::Pixmap(grid) => {
+                writeln!(f, "P3\n{} {}\n255", grid.cols(), grid.lines())?;
+                write_rows(f, grid, |(r, g, b)| format!("{} {} {}", r, g, b))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let bitmap = Pnm::Bitmap(Grid::from_fn(3, 2, |(x, y)| x == y));
+        assert_eq!(bitmap.to_string(), "P1\n3 2\n1 0 0\n0 1 0\n");
+        assert_eq!(bitmap.extension(), "pbm");
+
+        let graymap = Pnm::graymap(Grid::from_fn(2, 1, |(x, _)| x as u16 * 9));
+        assert_eq!(graymap.to_string(), "P2\n2 1\n9\n0 9\n");
+
+        let pixmap = Pnm::Pixmap(Grid::from_fn(2, 1, |(x, _)| [WHITE, BLACK][x as usize]));
+        assert_eq!(pixmap.to_string(), "P3\n2 1\n255\n255 255 255 0 0 0\n");
+
+        // 40 pixels need 79 chars, the row continues on a second line
+        let wide = Pnm::Bitmap(Grid::new(40, 1, false)).to_string();
+        let lines = wide.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines.iter().all(|line| line.len() <= 70));
+
+        assert_ne!(label_colour(0), label_colour(1));
+        assert!(label_colour(2).0 >= 0x40);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::generate::Rng;
+use std::fmt::Debug;
+
+// the same cases every run so a failure can be reproduced
+const SEED: u64 = 2021;
+
+// feeds generated cases to a naive and a clever implementation and panics with the
+// smallest case shrinking can find on which they disagree
+pub(crate) fn check_agree<T, R>(
+    cases: usize,
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    naive: impl Fn(&T) -> R,
+    clever: impl Fn(&T) -> R,
+) where
+    T: Debug,
+    R: PartialEq + Debug,
+{
+    let disagree = |case: &T| naive(case) != clever(case);
+
+    let mut rng = Rng::new(SEED);
+    for i in 0..cases {
+        let case = generate(&mut rng);
+        if !disagree(&case) {
+            continue;
+        }
+        let (case, steps) = minimize(case, &shrink, disagree);
+        panic!(
+            "case {} disagrees, shrunk {} times to {:?}\nnaive:  {:?}\nclever: {:?}",
+            i,
+            steps,
+            case,
+            naive(&case),
+            clever(&case)
+        );
+    }
+}
+
+// greedy, moves to the first smaller candidate that still fails until none does
+// candidates have to be strictly smaller or this never ends
+fn minimize<T>(
+    mut case: T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    fails: impl Fn(&T) -> bool,
+) -> (T, usize) {
+    let mut steps = 0;
+    while let Some(smaller) = shrink(&case).into_iter().find(|c| fails(c)) {
+        case = smaller;
+        steps += 1;
+    }
+    (case, steps)
+}
+
+// chunks of halving size removed first, then every item shrunk on its own
+pub(crate) fn shrink_vec<T: Clone>(items: &[T], shrink_item: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = vec![];
+    let mut chunk = items.len();
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let mut smaller = items[..start].to_vec();
+            smaller.extend_from_slice(&items[(start + chunk).min(items.len())..]);
+            candidates.push(smaller);
+        }
+        chunk /= 2;
+    }
+    for (i, item) in items.iter().enumerate() {
+        for smaller in shrink_item(item) {
+            let mut candidate = items.to_vec();
+            candidate[i] = smaller;
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+// towards 0, biggest jump first
+pub(crate) fn shrink_int(n: i64) -> Vec<i64> {
+    let mut candidates = vec![0, n / 2, n - n.signum()];
+    candidates.retain(|c| c.abs() < n.abs());
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::catch_unwind;
+
+    #[test]
+    fn it_works() {
+        assert_eq!(shrink_int(10), vec![0, 5, 9]);
+        assert_eq!(shrink_int(-3), vec![0, -1, -2]);
+        assert_eq!(shrink_int(1), vec![0]);
+        assert!(shrink_int(0).is_empty());
+        assert_eq!(
+            shrink_vec(&[1, 2], |_| vec![]),
+            vec![vec![], vec![2], vec![1]]
+        );
+
+        // a sum that is off as soon as a 7 or more is in the list shrinks to just [7]
+        let result = catch_unwind(|| {
+            check_agree(
+                100,
+                |rng| (0..10).map(|_| rng.range(0..=20)).collect::<Vec<_>>(),
+                |values| shrink_vec(values, |v| shrink_int(*v)),
+                |values| values.iter().sum::<i64>(),
+                |values| values.iter().map(|v| v.min(&6)).sum::<i64>(),
+            )
+        });
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("to [7]\n"), "{}", message);
+
+        check_agree(
+            100,
+            |rng| rng.range(-100..=100),
+            |n| shrink_int(*n),
+            |n| n * 2,
+            |n| n + n,
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::parse::Line;
+use crate::{ParseError, Solution};
+use std::io;
+use std::io::{BufRead, Write};
+
+// evaluates one line typed or pasted at the prompt, days with state like registers keep it
+// between lines
+pub trait Repl {
+    fn eval(&mut self, line: &Line) -> Result<String, ParseError>;
+}
+
+impl<F: FnMut(&Line) -> Result<String, ParseError>> Repl for F {
+    fn eval(&mut self, line: &Line) -> Result<String, ParseError> {
+        self(line)
+    }
+}
+
+pub type ReplFactory = fn() -> Box<dyn Repl>;
+
+// the line as the whole input of the day, errors point at the line of the session
+pub fn parse_line<S: Solution>(line: &Line) -> Result<S::Input, ParseError> {
+    S::parse(&[line.text.to_string()]).map_err(|e| ParseError {
+        line: line.number(),
+        ..e
+    })
+}
+
+// reads lines until the end of the input, errors are printed and the session goes on
+pub fn run(
+    day: u8,
+    repl: &mut dyn Repl,
+    input: impl BufRead,
+    out: &mut impl Write,
+    prompt: bool,
+) -> io::Result<()> {
+    let show_prompt = |out: &mut dyn Write| {
+        if prompt {
+            write!(out, "day{:02}> ", day)?;
+            out.flush()?;
+        }
+        Ok::<_, io::Error>(())
+    };
+
+    show_prompt(out)?;
+    for (index, text) in input.lines().enumerate() {
+        let text = text?;
+        let text = text.trim();
+        if !text.is_empty() {
+            let line = Line {
+                day,
+                section: None,
+                index,
+                text,
+            };
+            match repl.eval(&line) {
+                Ok(result) => writeln!(out, "{}", result)?,
+                Err(e) => writeln!(out, "{}", e)?,
+            }
+        }
+        show_prompt(out)?;
+    }
+    if prompt {
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let mut total = 0;
+        let mut adder = |line: &Line| {
+            total += line.parse::<i32>(line.text)?;
+            Ok(total.to_string())
+        };
+        let mut out = vec![];
+        run(
+            1,
+            &mut adder,
+            "1\n\n2\nx\n 3 \n".as_bytes(),
+            &mut out,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "1\n3\nday 1 line 4 column 1: invalid digit found in string `x'\n6\n"
+        );
+
+        let mut out = vec![];
+        run(1, &mut adder, "4\n".as_bytes(), &mut out, true).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "day01> 10\nday01> \n");
+    }
+}
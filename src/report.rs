@@ -0,0 +1,349 @@
+use crate::{Day, Part, NO_ANSWER};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Status {
+    Ok,
+    // the part ran fine but the input has no answer, like a bingo nobody wins
+    NoAnswer(String),
+    NoInput(String),
+    ParseError(String),
+    Panic(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::ParseError(_) | Status::Panic(_))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::NoAnswer(_) => "no_answer",
+            Status::NoInput(_) => "no_input",
+            Status::ParseError(_) => "parse_error",
+            Status::Panic(_) => "panic",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Status::Ok => "",
+            Status::NoAnswer(message)
+            | Status::NoInput(message)
+            | Status::ParseError(message)
+            | Status::Panic(message) => message,
+        }
+    }
+}
+
+// one row of the report, every part of every day gets one even if it never ran
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    // parse and solve time of the part
+    pub duration: Duration,
+    pub status: Status,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn run_day(day: &Day, parts: &[Part], lines: Result<Vec<String>, String>) -> Vec<Record> {
+    let record = |part: Part, answer, duration, status| Record {
+        day: day.day,
+        part,
+        answer,
+        duration,
+        status,
+    };
+    let failed = |duration, status: Status| {
+        parts
+            .iter()
+            .map(|part| record(*part, None, duration, status.clone()))
+            .collect()
+    };
+
+    let lines = match lines {
+        Ok(lines) => lines,
+        Err(message) => return failed(Duration::ZERO, Status::NoInput(message)),
+    };
+
+    let start = Instant::now();
+    let input = match catch_unwind(AssertUnwindSafe(|| day.runner.parse(&lines))) {
+        Ok(Ok(input)) => input,
+        Ok(Err(e)) => return failed(start.elapsed(), Status::ParseError(e.to_string())),
+        Err(payload) => return failed(start.elapsed(), Status::Panic(panic_message(payload))),
+    };
+    let parse_time = start.elapsed();
+
+    parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let result = catch_unwind(AssertUnwindSafe(|| day.runner.solve(input.as_ref(), *part)));
+            let duration = parse_time + start.elapsed();
+            match result {
+                Ok(answer) => match answer.strip_prefix(NO_ANSWER) {
+                    Some(reason) => {
+                        record(*part, None, duration, Status::NoAnswer(reason.to_string()))
+                    }
+                    None => record(*part, Some(answer), duration, Status::Ok),
+                },
+                Err(payload) => {
+                    record(*part, None, duration, Status::Panic(panic_message(payload)))
+                }
+            }
+        })
+        .collect()
+}
+
+// runs the days on a pool of threads, a panic only fails the day it happened in
+pub fn run_all<F>(days: &[&Day], parts: &[Part], threads: usize, load: F) -> Vec<Record>
+where
+    F: Fn(&Day) -> Result<Vec<String>, String> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let records = Mutex::new(vec![]);
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    // parts a day doesn't have are left out
+                    let day_parts = parts
+                        .iter()
+                        .filter(|part| day.runner.parts().contains(part))
+                        .copied()
+                        .collect::<Vec<_>>();
+                    let day_records = run_day(day, &day_parts, load(day));
+                    records.lock().unwrap().extend(day_records);
+                }
+            });
+        }
+    });
+
+    let mut records = records.into_inner().unwrap();
+    records.sort_by_key(|record| (record.day, record.part));
+    records
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+// report in the given format, durations are in nanoseconds
+pub struct Report<'a> {
+    pub format: Format,
+    pub records: &'a [Record],
+}
+
+struct JsonString<'a>(&'a str);
+
+impl Display for JsonString<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                '\n' => write!(f, "\\n")?,
+                '\r' => write!(f, "\\r")?,
+                '\t' => write!(f, "\\t")?,
+                c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+struct CsvField<'a>(&'a str);
+
+impl Display for CsvField<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.contains([',', '"', '\n', '\r']) {
+            write!(f, "\"{}\"", self.0.replace('"', "\"\""))
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.format {
+            Format::Json => {
+                writeln!(f, "[")?;
+                for (i, record) in self.records.iter().enumerate() {
+                    let answer = match &record.answer {
+                        Some(answer) => JsonString(answer).to_string(),
+                        None => "null".to_string(),
+                    };
+                    write!(
+                        f,
+                        "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"duration_ns\": {}, \"status\": \"{}\", \"error\": {}}}",
+                        record.day,
+                        record.part,
+                        answer,
+                        record.duration.as_nanos(),
+                        record.status.name(),
+                        JsonString(record.status.message())
+                    )?;
+                    writeln!(f, "{}", if i + 1 < self.records.len() { "," } else { "" })?;
+                }
+                writeln!(f, "]")
+            }
+            Format::Csv => {
+                writeln!(f, "day,part,answer,duration_ns,status,error")?;
+                for record in self.records.iter() {
+                    writeln!(
+                        f,
+                        "{},{},{},{},{},{}",
+                        record.day,
+                        record.part,
+                        CsvField(record.answer.as_deref().unwrap_or("")),
+                        record.duration.as_nanos(),
+                        record.status.name(),
+                        CsvField(record.status.message())
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Generator;
+    use crate::pnm::Pnm;
+    use crate::repl::ReplFactory;
+    use crate::{get_day, Example, Outcome, ParseError, Runner};
+
+    struct Panics;
+
+    impl Runner for Panics {
+        fn parts(&self) -> &'static [Part] {
+            &Part::ALL
+        }
+
+        fn examples(&self) -> &'static [Example] {
+            &[]
+        }
+
+        fn generator(&self) -> Option<Generator> {
+            None
+        }
+
+        fn parse(&self, _: &[String]) -> Result<Box<dyn Any + Send>, ParseError> {
+            Ok(Box::new(()))
+        }
+
+        fn solve(&self, _: &(dyn Any + Send), part: Part) -> String {
+            match part {
+                Part::One => panic!("boom"),
+                Part::Two => Outcome::<u8, _>(Err("nobody wins")).to_string(),
+            }
+        }
+
+        fn dump(&self, _: &(dyn Any + Send)) -> Option<Pnm> {
+            None
+        }
+
+        fn repl(&self) -> Option<ReplFactory> {
+            None
+        }
+
+        fn check(&self, _: &[String]) -> Vec<ParseError> {
+            vec![]
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let panics = Day {
+            day: 99,
+            runner: &Panics,
+        };
+        let days = [&panics, get_day(1).unwrap(), get_day(2).unwrap()];
+        let records = run_all(&days, &Part::ALL, 2, |day| match day.day {
+            2 => Err("no input".to_string()),
+            _ => Ok(day.example(None).map(|e| e.lines()).unwrap_or_default()),
+        });
+
+        let rows = records
+            .iter()
+            .map(|r| (r.day, r.part, r.answer.as_deref(), r.status.name()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (1, Part::One, Some("7"), "ok"),
+                (1, Part::Two, Some("5"), "ok"),
+                (2, Part::One, None, "no_input"),
+                (2, Part::Two, None, "no_input"),
+                (99, Part::One, None, "panic"),
+                (99, Part::Two, None, "no_answer"),
+            ]
+        );
+        assert_eq!(records[4].status, Status::Panic("boom".to_string()));
+        assert!(records[4].status.is_failure());
+        assert!(!records[2].status.is_failure());
+        assert_eq!(records[5].status.message(), "nobody wins");
+
+        let records = [Record {
+            day: 13,
+            part: Part::Two,
+            answer: Some("#.\n\"a,b\"".to_string()),
+            duration: Duration::from_nanos(42),
+            status: Status::Ok,
+        }];
+        let csv = Report {
+            format: Format::Csv,
+            records: &records,
+        };
+        assert_eq!(
+            csv.to_string(),
+            "day,part,answer,duration_ns,status,error\n13,2,\"#.\n\"\"a,b\"\"\",42,ok,\n"
+        );
+        let json = Report {
+            format: Format::Json,
+            records: &records,
+        };
+        assert_eq!(
+            json.to_string(),
+            "[\n  {\"day\": 13, \"part\": 2, \"answer\": \"#.\\n\\\"a,b\\\"\", \"duration_ns\": 42, \"status\": \"ok\", \"error\": \"\"}\n]\n"
+        );
+    }
+}
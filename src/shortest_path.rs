@@ -0,0 +1,124 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+// cost of the cheapest path and the nodes along it, start and goal included
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+// successors yields (node, cost of the step) pairs, costs must not be negative
+pub fn dijkstra<N, C, S, I>(
+    start: N,
+    successors: S,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+// heuristic must never overestimate the remaining cost or the path may not be the cheapest
+pub fn astar<N, C, S, I>(
+    start: N,
+    mut successors: S,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs: HashMap<N, C> = HashMap::new();
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut queue = PriorityQueue::new();
+
+    costs.insert(start.clone(), C::default());
+    let estimate = heuristic(&start);
+    queue.push(start, Reverse(estimate));
+
+    while let Some((node, _)) = queue.pop() {
+        let cost = costs[&node];
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&parents, node),
+            });
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|known| next_cost < *known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                let estimate = next_cost + heuristic(&next);
+                // lowers the priority if next is already queued
+                queue.push_increase(next, Reverse(estimate));
+            }
+        }
+    }
+    None
+}
+
+fn reconstruct<N: Clone + Hash + Eq>(parents: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(parent) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        // a -1-> b -1-> d is cheaper than a -5-> d, c is a dead end
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 5), ('c', 1)]),
+            ('b', vec![('d', 1)]),
+            ('c', vec![]),
+            ('d', vec![]),
+        ]);
+        let path = dijkstra('a', |n| edges[n].clone(), |n| *n == 'd').unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 2,
+                nodes: vec!['a', 'b', 'd']
+            }
+        );
+        assert_eq!(dijkstra('c', |n| edges[n].clone(), |n| *n == 'd'), None);
+
+        // manhattan distance on an open 10x10 field
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+                .into_iter()
+                .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                .map(|pos| (pos, 1))
+        };
+        let path = astar(
+            (0, 0),
+            successors,
+            |(x, y)| (9 - x) + (9 - y),
+            |pos| *pos == (9, 9),
+        )
+        .unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&(9, 9)));
+    }
+}
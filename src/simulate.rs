@@ -0,0 +1,191 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use thiserror::Error;
+
+// when a simulation is done, cycles end every kind of simulation so none runs forever
+pub enum Stop<'a, S> {
+    // after exactly this many steps
+    After(usize),
+    // the first state after the start the predicate holds for
+    When(&'a dyn Fn(&S) -> bool),
+    // the first state a step doesn't change
+    Fixpoint,
+    // the first state that comes back later
+    Cycle,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    // the After or When condition was reached
+    Stopped,
+    // the state maps to itself
+    Fixpoint,
+    // the state at step start comes back every period steps, period is more than 1
+    Cycle { start: usize, period: usize },
+}
+
+// a simulation that went round in a cycle instead of reaching what it was run for
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
+#[error("the state at step {start} comes back every {period} steps")]
+pub struct Cycled {
+    pub start: usize,
+    pub period: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Simulation<S> {
+    pub state: S,
+    // number of steps from the start state to state
+    pub steps: usize,
+    pub outcome: Outcome,
+}
+
+fn hash_of<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn advance<S>(mut state: S, steps: usize, step: &mut impl FnMut(&S) -> S) -> S {
+    for _ in 0..steps {
+        state = step(&state);
+    }
+    state
+}
+
+// runs step from start until stop, a state equal to the previous one is a fixpoint and
+// longer cycles are found with Brent's algorithm on the hashed states. step has to be pure,
+// finding where a cycle starts runs it again from start, so counters or recorded frames
+// belong outside of it
+pub fn simulate<S>(start: S, mut step: impl FnMut(&S) -> S, stop: Stop<S>) -> Simulation<S>
+where
+    S: Clone + Eq + Hash,
+{
+    let stopped = |state, steps| Simulation {
+        state,
+        steps,
+        outcome: Outcome::Stopped,
+    };
+    if let Stop::After(0) = stop {
+        return stopped(start, 0);
+    }
+
+    let mut state = start.clone();
+    let mut state_hash = hash_of(&state);
+    let mut tortoise = start.clone();
+    let mut tortoise_hash = state_hash;
+    let mut power = 1;
+    let mut period = 0;
+    let mut steps = 0;
+
+    loop {
+        let next = step(&state);
+        let next_hash = hash_of(&next);
+        steps += 1;
+        period += 1;
+
+        match stop {
+            Stop::After(count) if steps == count => return stopped(next, steps),
+            Stop::When(done) if done(&next) => return stopped(next, steps),
+            _ => {}
+        }
+        if next_hash == state_hash && next == state {
+            // the state at steps - 1 is a fixpoint, After can be answered right away
+            return Simulation {
+                state: next,
+                steps: match stop {
+                    Stop::After(count) => count,
+                    _ => steps - 1,
+                },
+                outcome: Outcome::Fixpoint,
+            };
+        }
+        if next_hash == tortoise_hash && next == tortoise {
+            break;
+        }
+
+        state = next;
+        state_hash = next_hash;
+        if period == power {
+            tortoise = state.clone();
+            tortoise_hash = state_hash;
+            power *= 2;
+            period = 0;
+        }
+    }
+
+    // the first state of the cycle is where a walker period steps ahead meets one from start
+    let mut first = start;
+    let mut ahead = advance(first.clone(), period, &mut step);
+    let mut cycle_start = 0;
+    while first != ahead {
+        first = step(&first);
+        ahead = step(&ahead);
+        cycle_start += 1;
+    }
+    let outcome = Outcome::Cycle {
+        start: cycle_start,
+        period,
+    };
+
+    match stop {
+        // every state of the cycle was seen already, the rest is just going round
+        Stop::After(count) => Simulation {
+            state: advance(first, (count - cycle_start) % period, &mut step),
+            steps: count,
+            outcome,
+        },
+        _ => Simulation {
+            state: first,
+            steps: cycle_start,
+            outcome,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        // 0 1 2 3 4 5 6 4 5 6 ...
+        let step = |n: &u32| if *n == 6 { 4 } else { n + 1 };
+        let cycle = simulate(0, step, Stop::Cycle);
+        assert_eq!(
+            cycle.outcome,
+            Outcome::Cycle {
+                start: 4,
+                period: 3
+            }
+        );
+        assert_eq!((cycle.state, cycle.steps), (4, 4));
+
+        // skips ahead instead of running a million steps, 1000000 - 4 = 3 * 333332
+        let after = simulate(0, step, Stop::After(1_000_000));
+        assert_eq!((after.state, after.steps), (4, 1_000_000));
+        let after = simulate(0, step, Stop::After(5));
+        assert_eq!((after.state, after.outcome), (5, Outcome::Stopped));
+
+        let halving = |n: &u32| n / 2;
+        let fixpoint = simulate(100, halving, Stop::Fixpoint);
+        assert_eq!(
+            (fixpoint.state, fixpoint.steps, fixpoint.outcome),
+            (0, 7, Outcome::Fixpoint)
+        );
+
+        let when = simulate(100, halving, Stop::When(&|n| *n < 10));
+        assert_eq!((when.state, when.steps), (6, 4));
+        assert_eq!(simulate(3, halving, Stop::When(&|n| *n < 10)).steps, 1);
+
+        // a condition that never holds ends with the cycle
+        let never = simulate(0, step, Stop::When(&|n| *n > 6));
+        assert_eq!(
+            never.outcome,
+            Outcome::Cycle {
+                start: 4,
+                period: 3
+            }
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::io;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+// one state of a simulation as it would be printed
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Frame {
+    pub title: String,
+    pub text: String,
+}
+
+thread_local! {
+    // Some while a recording runs on this thread
+    static FRAMES: RefCell<Option<Vec<Frame>>> = const { RefCell::new(None) };
+}
+
+pub fn is_recording() -> bool {
+    FRAMES.with(|frames| frames.borrow().is_some())
+}
+
+// adds a frame to the running recording, text is only rendered if there is one
+// so simulations can call this every step for free
+pub fn record(title: impl Display, text: impl FnOnce() -> String) {
+    FRAMES.with(|frames| {
+        if let Some(frames) = frames.borrow_mut().as_mut() {
+            frames.push(Frame {
+                title: title.to_string(),
+                text: text(),
+            });
+        }
+    });
+}
+
+// runs f with recording enabled, returns its result and the frames it recorded
+pub fn recording<T>(f: impl FnOnce() -> T) -> (T, Vec<Frame>) {
+    let outer = FRAMES.with(|frames| frames.borrow_mut().replace(vec![]));
+    let result = f();
+    let frames = FRAMES.with(|frames| frames.replace(outer));
+    (result, frames.unwrap_or_default())
+}
+
+// clears the terminal before every frame and waits 1 / fps seconds after it
+pub fn play(frames: &[Frame], fps: f64, out: &mut impl Write) -> io::Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / fps);
+    for (i, frame) in frames.iter().enumerate() {
+        write!(out, "\x1b[2J\x1b[H")?;
+        writeln!(out, "frame {}/{}: {}", i + 1, frames.len(), frame.title)?;
+        write!(out, "{}", frame.text)?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+// a header line per frame followed by its text, to step through with a pager or diff
+pub fn write_frames(frames: &[Frame], out: &mut impl Write) -> io::Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        writeln!(out, "=== frame {}: {}", i + 1, frame.title)?;
+        write!(out, "{}", frame.text)?;
+        if !frame.text.is_empty() && !frame.text.ends_with('\n') {
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        // nothing is rendered without a recording
+        record("step 0", || panic!("rendered"));
+        assert!(!is_recording());
+
+        let (answer, frames) = recording(|| {
+            for step in 0..3 {
+                record(format!("step {}", step), || "#".repeat(step) + "\n");
+            }
+            // nested recordings keep their frames apart
+            let (_, inner) = recording(|| record("inner", String::new));
+            assert_eq!(inner.len(), 1);
+            assert!(is_recording());
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[2].text, "##\n");
+        assert!(!is_recording());
+
+        let mut file = vec![];
+        write_frames(&frames[1..], &mut file).unwrap();
+        assert_eq!(
+            String::from_utf8(file).unwrap(),
+            "=== frame 1: step 1\n#\n=== frame 2: step 2\n##\n"
+        );
+
+        let mut terminal = vec![];
+        play(&frames[1..2], 1000.0, &mut terminal).unwrap();
+        assert_eq!(
+            String::from_utf8(terminal).unwrap(),
+            "\x1b[2J\x1b[Hframe 1/1: step 1\n#\n"
+        );
+    }
+}
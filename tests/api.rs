@@ -0,0 +1,38 @@
+use aoc_2021::day04::{winners, Day04, Rules};
+use aoc_2021::day16::{evaluate, version_sum, Day16};
+use aoc_2021::day18::{reduce, Day18, List};
+use aoc_2021::day22::{intersection, MyCuboid};
+use aoc_2021::Solution;
+
+fn lines(input: &str) -> Vec<String> {
+    input.lines().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn it_works() {
+    // the top left to bottom right diagonal only wins with diagonals on
+    let bingo = Day04::parse(&lines("1,5,9\n\n1 2 3\n4 5 6\n7 8 9")).unwrap();
+    assert!(winners(&bingo, Rules::default()).is_err());
+    let (first, last) = winners(&bingo, Rules { diagonals: true }).unwrap();
+    assert_eq!((first.board, first.draw, first.score), (0, 2, 270));
+    assert_eq!(first, last);
+
+    let transmission = Day16::parse(&lines("9C0141080250320F1802104A08")).unwrap();
+    assert_eq!(evaluate(&transmission), Ok(1));
+    assert_eq!(version_sum(&transmission), 20);
+
+    let numbers = Day18::parse(&lines("[[[[[9,8],1],2],3],4]")).unwrap();
+    let mut list = List::root(numbers[0].clone());
+    let pair = list.find(list.get_first_pair(0).unwrap()).unwrap().clone();
+    list.explode(&pair);
+    assert_eq!(list.to_string(), "[[[[0,9],2],3],4]");
+    reduce(&mut list);
+    assert_eq!(list.calc_magnitude(), 548);
+
+    let a = MyCuboid::new_from_range(0..=2, 0..=2, 0..=2, 1);
+    let b = MyCuboid::new_from_range(1..=3, 1..=3, 1..=3, 1);
+    let overlap = intersection(&a, &b).unwrap();
+    assert_eq!(overlap.volume(), -8);
+    let far = MyCuboid::new_from_range(5..=6, 5..=6, 5..=6, 1);
+    assert!(intersection(&a, &far).is_none());
+}